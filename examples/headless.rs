@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use bevy::app::ScheduleRunnerPlugin;
+use bevy::log::LogPlugin;
+use bevy::prelude::*;
+use bevy::winit::WinitPlugin;
+use bevy_ratatui_camera::RatatuiCamera;
+use bevy_ratatui_camera::RatatuiCameraHeadlessPlugin;
+use bevy_ratatui_camera::RatatuiCameraStrategy;
+use bevy_ratatui_camera::RatatuiCameraWidget;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::Widget;
+
+mod shared;
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins
+                .build()
+                .disable::<WinitPlugin>()
+                .disable::<LogPlugin>(),
+            ScheduleRunnerPlugin::run_loop(Duration::from_secs_f64(1. / 60.)),
+            RatatuiCameraHeadlessPlugin,
+        ))
+        .insert_resource(ClearColor(Color::BLACK))
+        .add_systems(Startup, setup_scene_system)
+        .add_systems(Update, print_scene_system)
+        .run();
+}
+
+fn setup_scene_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    shared::spawn_3d_scene(&mut commands, &mut meshes, &mut materials);
+
+    commands.spawn((
+        RatatuiCamera::new((80, 48)),
+        RatatuiCameraStrategy::luminance_misc(),
+        Camera3d::default(),
+        Transform::from_xyz(3., 3., 3.).looking_at(Vec3::ZERO, Vec3::Z),
+    ));
+}
+
+/// Once a few frames have been rendered, draws the camera widget into a plain ratatui buffer
+/// instead of a terminal, prints its characters to stdout, and exits.
+fn print_scene_system(
+    ratatui_camera_widget: Query<&RatatuiCameraWidget>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let Ok(camera_widget) = ratatui_camera_widget.get_single() else {
+        return;
+    };
+
+    if camera_widget.frame < 10 {
+        return;
+    }
+
+    let area = Rect::new(0, 0, 80, 24);
+    let mut buffer = Buffer::empty(area);
+    camera_widget.render(area, &mut buffer);
+
+    for y in area.top()..area.bottom() {
+        let line = (area.left()..area.right())
+            .map(|x| buffer[(x, y)].symbol())
+            .collect::<String>();
+
+        println!("{line}");
+    }
+
+    exit.send_default();
+}
@@ -51,18 +51,18 @@ fn setup_scene_system(
     shared::spawn_3d_scene(&mut commands, &mut meshes, &mut materials);
 
     commands.spawn((
-        RatatuiCamera::default(),
+        RatatuiCamera::autoresize(),
         RatatuiCameraStrategy::Luminance(LuminanceConfig::default()),
         Camera3d::default(),
         Transform::from_xyz(0., 3., 0.).looking_at(Vec3::ZERO, Vec3::Z),
     ));
     commands.spawn((
-        RatatuiCamera::default(),
+        RatatuiCamera::autoresize(),
         Camera3d::default(),
         Transform::from_xyz(0., 0., 3.).looking_at(Vec3::ZERO, Vec3::Z),
     ));
     commands.spawn((
-        RatatuiCamera::default(),
+        RatatuiCamera::autoresize(),
         RatatuiCameraStrategy::Luminance(LuminanceConfig::default()),
         Camera3d::default(),
         Transform::from_xyz(2., 2., 2.).looking_at(Vec3::ZERO, Vec3::Z),
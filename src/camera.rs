@@ -1,4 +1,7 @@
-use bevy::prelude::*;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bevy::{ecs::system::SystemId, prelude::*};
 
 /// Spawn this component with your bevy camera in order to send each frame's rendered image to
 /// a RatatuiCameraWidget that will be inserted into the same camera entity.
@@ -10,13 +13,22 @@ pub struct RatatuiCamera {
     pub dimensions: (u32, u32),
 
     /// If true, the rendered image dimensions will be resized to match the size and aspect ratio
-    /// of the terminal window (at startup and whenever a terminal resize event is received).
+    /// of the area the camera's widget was last drawn in, or of the terminal window before the
+    /// widget has been drawn (at startup and whenever either size changes).
     pub autoresize: bool,
 
-    /// When autoresize is true, this function will be used to transform the new terminal
-    /// dimensions into the rendered image dimensions. For example, use `|(w, h)| (w*4, h*3)` to
-    /// maintain a 4:3 aspect ratio.
-    pub autoresize_fn: fn((u32, u32)) -> (u32, u32),
+    /// When autoresize is true, this policy will be used to transform the new area dimensions
+    /// into the rendered image dimensions. The policy is reapplied whenever it is changed, so it
+    /// can be updated from a system to change the render scale.
+    pub autoresize_policy: AutoresizePolicy,
+
+    /// Controls how often the camera renders and reads back a new frame. Idle cameras skip their
+    /// readback, and are made inactive for the frame if they own their render target.
+    pub render_policy: RatatuiCameraRenderPolicy,
+
+    /// Where the rendered frames are read back from. By default the camera's render target is
+    /// replaced, but the camera can instead mirror its existing target (e.g. a window).
+    pub source: RatatuiCameraSource,
 }
 
 impl Default for RatatuiCamera {
@@ -24,7 +36,9 @@ impl Default for RatatuiCamera {
         Self {
             dimensions: (256, 256),
             autoresize: false,
-            autoresize_fn: |(w, h)| (w * 2, h * 2),
+            autoresize_policy: AutoresizePolicy::default(),
+            render_policy: RatatuiCameraRenderPolicy::default(),
+            source: RatatuiCameraSource::default(),
         }
     }
 }
@@ -54,17 +68,182 @@ impl RatatuiCamera {
         self
     }
 
-    pub fn with_autoresize_fn(mut self, autoresize_fn: fn((u32, u32)) -> (u32, u32)) -> Self {
-        self.autoresize_fn = autoresize_fn;
+    pub fn with_autoresize_fn(
+        mut self,
+        autoresize_fn: impl Fn((u32, u32)) -> (u32, u32) + Send + Sync + 'static,
+    ) -> Self {
+        self.autoresize_policy = AutoresizePolicy::Custom(Arc::new(autoresize_fn));
+        self
+    }
+
+    pub fn with_autoresize_policy(mut self, autoresize_policy: AutoresizePolicy) -> Self {
+        self.autoresize_policy = autoresize_policy;
+        self
+    }
+
+    pub fn with_render_policy(mut self, render_policy: RatatuiCameraRenderPolicy) -> Self {
+        self.render_policy = render_policy;
         self
     }
+
+    pub fn with_source(mut self, source: RatatuiCameraSource) -> Self {
+        self.source = source;
+        self
+    }
+}
+
+/// Specify where a RatatuiCamera reads its rendered frames from.
+///
+#[derive(Default, Clone)]
+pub enum RatatuiCameraSource {
+    /// The camera's render target is replaced with an image of the RatatuiCamera's dimensions,
+    /// which is read back every frame.
+    #[default]
+    Owned,
+
+    /// The camera keeps its existing render target (for example a window), and frames are read
+    /// back from the camera's view target. The RatatuiCamera's dimensions will follow the size of
+    /// the render target, so a desktop build can render to its window and the terminal at once.
+    MirrorView,
+
+    /// The camera keeps its existing render target, and frames are read back from the provided
+    /// image, such as an image that the camera (or another camera) renders to. The
+    /// RatatuiCamera's dimensions will follow the size of the image.
+    MirrorImage(Handle<Image>),
+}
+
+/// Specify how the dimensions of the area a RatatuiCamera's widget is drawn in are transformed
+/// into rendered image dimensions when the camera autoresizes. Area dimensions are given in
+/// pixels, where each terminal cell is one pixel wide and two pixels tall.
+///
+#[derive(Clone)]
+pub enum AutoresizePolicy {
+    /// Render one pixel for each pixel of the area (the widget's last drawn area, or the terminal
+    /// before the widget has been drawn).
+    FitTerminal,
+
+    /// Render the largest image with the given aspect ratio (width, height) that fits inside the
+    /// area.
+    FixedAspect(u32, u32),
+
+    /// Multiply the area dimensions by the given scale.
+    Scale(f32),
+
+    /// Fit the area, but scale down (maintaining aspect ratio) to stay within the given number of
+    /// total pixels.
+    MaxPixels(u32),
+
+    /// Transform the area dimensions with a closure, which can capture state.
+    Custom(Arc<dyn Fn((u32, u32)) -> (u32, u32) + Send + Sync>),
+
+    /// Transform the area dimensions with a registered one-shot system, which can read ECS state
+    /// (for example a quality setting resource).
+    System(SystemId<In<(u32, u32)>, (u32, u32)>),
+}
+
+impl Default for AutoresizePolicy {
+    fn default() -> Self {
+        Self::Scale(2.0)
+    }
+}
+
+impl AutoresizePolicy {
+    /// Applies the policy to the given area dimensions, returning the new image dimensions.
+    pub(crate) fn resize(
+        &self,
+        (width, height): (u32, u32),
+        world: &mut World,
+    ) -> Option<(u32, u32)> {
+        let (new_width, new_height) = match self {
+            AutoresizePolicy::FitTerminal => (width, height),
+            AutoresizePolicy::FixedAspect(aspect_width, aspect_height) => {
+                let scale = (width as f32 / *aspect_width as f32)
+                    .min(height as f32 / *aspect_height as f32);
+                (
+                    (*aspect_width as f32 * scale) as u32,
+                    (*aspect_height as f32 * scale) as u32,
+                )
+            }
+            AutoresizePolicy::Scale(scale) => (
+                (width as f32 * scale) as u32,
+                (height as f32 * scale) as u32,
+            ),
+            AutoresizePolicy::MaxPixels(max_pixels) => {
+                let scale = (*max_pixels as f32 / (width * height) as f32)
+                    .sqrt()
+                    .min(1.0);
+                (
+                    (width as f32 * scale) as u32,
+                    (height as f32 * scale) as u32,
+                )
+            }
+            AutoresizePolicy::Custom(autoresize_fn) => autoresize_fn((width, height)),
+            AutoresizePolicy::System(system_id) => world
+                .run_system_with_input(*system_id, (width, height))
+                .ok()?,
+        };
+
+        Some((new_width.max(1), new_height.max(1)))
+    }
+}
+
+/// Specify how often a RatatuiCamera should render a new frame. The terminal often cannot display
+/// frames as quickly as bevy can render them, and mostly static scenes do not need to be rendered
+/// and converted every frame.
+///
+#[derive(Default, Clone, Copy)]
+pub enum RatatuiCameraRenderPolicy {
+    /// Render and read back a new frame every frame.
+    #[default]
+    EveryFrame,
+
+    /// Render at most the specified number of frames per second. Zero, negative, or NaN values
+    /// render no new frames, except when the `RatatuiCamera` component is changed.
+    MaxFps(f32),
+
+    /// Only render when a `RatatuiCameraRedraw` event is sent for this camera, or when the
+    /// `RatatuiCamera` component is changed. If `detect_changes` is true, the camera will also
+    /// render whenever a transform, mesh, or mesh asset changes in the world.
+    OnDemand { detect_changes: bool },
+}
+
+impl RatatuiCameraRenderPolicy {
+    /// The minimum time between frames for `MaxFps`, or `None` if the frame rate is not positive.
+    pub(crate) fn frame_interval(&self) -> Option<Duration> {
+        match *self {
+            RatatuiCameraRenderPolicy::MaxFps(fps) => Duration::try_from_secs_f32(fps.recip()).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Send this event to request a new frame from cameras using `RatatuiCameraRenderPolicy::OnDemand`.
+///
+#[derive(Event, Clone, Copy, Default)]
+pub struct RatatuiCameraRedraw {
+    /// The camera entity to redraw, or every on-demand camera if `None`.
+    pub camera: Option<Entity>,
+}
+
+impl RatatuiCameraRedraw {
+    /// Request a new frame from every on-demand camera.
+    pub fn all() -> Self {
+        Self { camera: None }
+    }
+
+    /// Request a new frame from a specific camera entity.
+    pub fn camera(camera: Entity) -> Self {
+        Self {
+            camera: Some(camera),
+        }
+    }
 }
 
 /// Specify the strategy used for converting the camera's rendered image to unicode characters for
 /// the terminal buffer. Insert a variant of this component alongside your `RatatuiCamera` to
 /// change the default behavior.
 ///
-#[derive(Component, Default, Clone)]
+#[derive(Component, Default, Clone, PartialEq)]
 pub enum RatatuiCameraStrategy {
     /// Print to the terminal using unicode halfblock characters. By using both the halfblock
     /// (foreground) color and the background color, we can draw two pixels per buffer cell.
@@ -128,7 +307,7 @@ impl RatatuiCameraStrategy {
 /// # };
 /// ```
 ///
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 pub struct LuminanceConfig {
     /// The list of characters, in increasing order of opacity, to use for printing. For example,
     /// put an '@' symbol after a '+' symbol because it is more "opaque", taking up more space in
@@ -166,3 +345,36 @@ impl Default for LuminanceConfig {
         }
     }
 }
+
+/// Specify how transparent pixels in the camera's rendered image are drawn. Insert a variant of
+/// this component alongside your `RatatuiCamera` to change the default behavior.
+///
+/// For the rendered image to contain transparent pixels, the camera must be cleared to a
+/// transparent color, for example with `ClearColorConfig::Custom(Color::NONE)`. Pixels with an
+/// alpha value below one half are considered transparent.
+///
+#[derive(Component, Default, Clone, Copy, PartialEq)]
+pub enum RatatuiCameraTransparency {
+    /// The alpha channel is ignored, and every cell in the drawn area is overwritten.
+    #[default]
+    Opaque,
+
+    /// Cells where the image is transparent are left untouched, so that whatever was already drawn
+    /// in the buffer shows through. For the halfblocks strategy, cells where only one half is
+    /// transparent only have their foreground color set.
+    Transparent,
+
+    /// Cells where the image is transparent are cleared and given the terminal's default colors
+    /// (`Color::Reset`).
+    Reset,
+}
+
+impl RatatuiCameraTransparency {
+    /// Alpha values below this are considered transparent.
+    pub(crate) const ALPHA_THRESHOLD: u8 = 128;
+
+    /// Whether a pixel with this alpha value should be treated as transparent.
+    pub(crate) fn is_transparent(&self, alpha: u8) -> bool {
+        *self != Self::Opaque && alpha < Self::ALPHA_THRESHOLD
+    }
+}
@@ -0,0 +1,189 @@
+use std::sync::Arc;
+
+use bevy::ecs::component::Tick;
+use bevy::prelude::*;
+use bevy_ratatui::event::InputSet;
+
+use crate::widget::CellsSource;
+use crate::{RatatuiCamera, RatatuiCameraCells, RatatuiCameraWidget};
+
+pub struct RatatuiCameraCellsPlugin;
+
+impl Plugin for RatatuiCameraCellsPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            PreUpdate,
+            (RatatuiCameraSet::Convert, RatatuiCameraSet::PostProcess)
+                .chain()
+                .after(InputSet::EmitCrossterm),
+        )
+        .add_systems(
+            PreUpdate,
+            (
+                (
+                    insert_ratatui_camera_cells_system,
+                    convert_ratatui_camera_cells_system,
+                )
+                    .chain()
+                    .in_set(RatatuiCameraSet::Convert),
+                store_ratatui_camera_cells_system.after(RatatuiCameraSet::PostProcess),
+            ),
+        );
+    }
+}
+
+/// System sets for hooking into the conversion of each RatatuiCamera's rendered image into cells,
+/// which happens in the `PreUpdate` schedule (after terminal input events are emitted) for every
+/// RatatuiCameraWidget that has been drawn at least once.
+///
+/// Systems added to `RatatuiCameraSet::PostProcess` can mutate the `RatatuiCameraCells`
+/// component of a RatatuiCamera entity after conversion and before the widget is drawn, for effects
+/// that need to touch characters or modifiers (scanlines, vignettes, palette cycling, highlighting
+/// the cell under the mouse, etc.). The cells are reset to a fresh conversion every frame after
+/// they are changed, so changes do not accumulate.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiCameraCells, RatatuiCameraSet};
+/// # use ratatui::style::Modifier;
+/// #
+/// # fn build(app: &mut App) {
+/// app.add_systems(PreUpdate, scanlines_system.in_set(RatatuiCameraSet::PostProcess));
+/// # }
+///
+/// fn scanlines_system(mut ratatui_cameras: Query<&mut RatatuiCameraCells>) {
+///     for mut cells in &mut ratatui_cameras {
+///         for (position, cell) in cells.iter_mut() {
+///             if position.y % 2 == 1 {
+///                 cell.modifier |= Modifier::DIM;
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum RatatuiCameraSet {
+    /// Converts the last rendered image of each RatatuiCamera into its `RatatuiCameraCells`
+    /// component, for the area its widget was last drawn in. Conversion is skipped (and the cells
+    /// from the last conversion are reused) until a new frame is received or the area or
+    /// conversion settings change.
+    Convert,
+
+    /// Runs after conversion and before the cells are stored in the RatatuiCameraWidget for
+    /// drawing.
+    PostProcess,
+}
+
+/// The last cells converted for a RatatuiCamera, along with what they were converted from, so that
+/// conversion can be skipped until the frame, area, or conversion settings change.
+#[derive(Component, Default)]
+pub struct RatatuiCameraCellsCache(Option<CachedCells>);
+
+pub struct CachedCells {
+    source: CellsSource,
+    cells: Arc<RatatuiCameraCells>,
+
+    /// When the RatatuiCameraCells component was last reset to the cached cells. If it has changed
+    /// since, it was post-processed.
+    reset_tick: Option<Tick>,
+}
+
+fn insert_ratatui_camera_cells_system(
+    mut commands: Commands,
+    ratatui_cameras: Query<
+        (Entity, &RatatuiCameraWidget),
+        (With<RatatuiCamera>, Without<RatatuiCameraCellsCache>),
+    >,
+) {
+    for (entity_id, widget) in &ratatui_cameras {
+        if widget.last_area().is_some() {
+            commands.entity(entity_id).insert((
+                RatatuiCameraCellsCache::default(),
+                RatatuiCameraCells::default(),
+            ));
+        }
+    }
+}
+
+/// Converts each camera's image only when it has changed since the last conversion (a new frame,
+/// a different area, or different settings), in parallel across cameras. The cells component is
+/// reset from the cache after a new conversion or after it was post-processed, so that
+/// post-processing always starts from a clean conversion.
+fn convert_ratatui_camera_cells_system(
+    mut ratatui_cameras: Query<
+        (
+            &RatatuiCameraWidget,
+            &mut RatatuiCameraCellsCache,
+            &mut RatatuiCameraCells,
+        ),
+        With<RatatuiCamera>,
+    >,
+) {
+    ratatui_cameras
+        .par_iter_mut()
+        .for_each(|(widget, mut cache, mut cells)| {
+            let Some(last_area) = widget.last_area() else {
+                return;
+            };
+
+            let cache = cache.bypass_change_detection();
+            let is_cache_valid = cache
+                .0
+                .as_ref()
+                .is_some_and(|cached| cached.source.matches(widget, last_area));
+
+            if !is_cache_valid {
+                cache.0 = Some(CachedCells {
+                    source: CellsSource::new(widget, last_area),
+                    cells: Arc::new(widget.cells(last_area)),
+                    reset_tick: None,
+                });
+            }
+
+            let Some(cached) = cache.0.as_mut() else {
+                return;
+            };
+
+            if cached.reset_tick != Some(cells.last_changed()) {
+                cells.clone_from(&cached.cells);
+                cached.reset_tick = Some(cells.last_changed());
+            }
+        });
+}
+
+/// Stores the cells to draw in each widget, sharing the cached conversion unless the cells
+/// component was post-processed.
+fn store_ratatui_camera_cells_system(
+    mut ratatui_cameras: Query<
+        (
+            &mut RatatuiCameraWidget,
+            Ref<RatatuiCameraCells>,
+            &RatatuiCameraCellsCache,
+        ),
+        With<RatatuiCamera>,
+    >,
+) {
+    for (mut widget, cells, cache) in &mut ratatui_cameras {
+        let Some(cached) = cache.0.as_ref() else {
+            continue;
+        };
+
+        let is_post_processed = cached.reset_tick != Some(cells.last_changed());
+
+        let is_stored = widget.cells.as_ref().is_some_and(|(_, stored_cells)| {
+            !is_post_processed && Arc::ptr_eq(stored_cells, &cached.cells)
+        });
+
+        if !is_stored {
+            let stored_cells = match is_post_processed {
+                true => Arc::new(cells.clone()),
+                false => cached.cells.clone(),
+            };
+
+            widget.cells = Some((cached.source.clone(), stored_cells));
+        }
+    }
+}
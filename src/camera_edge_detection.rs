@@ -9,7 +9,7 @@ use bevy::{prelude::*, render::extract_component::ExtractComponent};
 ///
 /// Currently just works with `RatatuiCameraStrategy::Luminance` and 3d cameras.
 ///
-#[derive(Component, ExtractComponent, Clone, Copy)]
+#[derive(Component, ExtractComponent, Clone, Copy, PartialEq)]
 pub struct RatatuiCameraEdgeDetection {
     /// Width of the range used for detecting edges. Higher thickness value means a wider edge.
     pub thickness: f32,
@@ -57,7 +57,7 @@ impl Default for RatatuiCameraEdgeDetection {
 
 /// Specify how to handle rendering detected edges as unicode characters.
 ///
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq)]
 pub enum EdgeCharacters {
     /// Each character in a detected edge will be shown as a specified character.
     Single(char),
@@ -82,3 +82,41 @@ impl Default for EdgeCharacters {
         }
     }
 }
+
+impl EdgeCharacters {
+    /// Selects the character for a pixel of the sobel image, whose channels hold the strength of
+    /// vertical, horizontal, forward diagonal and backward diagonal edges. Returns `None` if no
+    /// edge was detected.
+    pub(crate) fn for_sobel_value(&self, sobel_value: [u8; 4]) -> Option<char> {
+        match *self {
+            EdgeCharacters::Directional {
+                vertical,
+                horizontal,
+                forward_diagonal,
+                backward_diagonal,
+            } => {
+                let is_max_sobel = |current: u8| {
+                    sobel_value
+                        .iter()
+                        .all(|val| (current > 0) && (current >= *val))
+                };
+
+                if is_max_sobel(sobel_value[0]) {
+                    Some(vertical)
+                } else if is_max_sobel(sobel_value[1]) {
+                    Some(horizontal)
+                } else if is_max_sobel(sobel_value[2]) {
+                    Some(forward_diagonal)
+                } else if is_max_sobel(sobel_value[3]) {
+                    Some(backward_diagonal)
+                } else {
+                    None
+                }
+            }
+            EdgeCharacters::Single(edge_character) => sobel_value
+                .iter()
+                .any(|val| *val > 0)
+                .then_some(edge_character),
+        }
+    }
+}
@@ -9,63 +9,153 @@ use bevy::{
         },
         renderer::RenderDevice,
     },
+    utils::Instant,
 };
 use crossbeam_channel::{Receiver, Sender};
 
 #[derive(Clone)]
 pub struct ImageSender {
-    pub sender: Sender<Vec<u8>>,
-    pub sender_image: Handle<Image>,
+    pub sender: Sender<(Vec<u8>, Instant)>,
+    /// Image copied into the buffer each frame, or None to copy the camera's view target.
+    pub sender_image: Option<Handle<Image>>,
     pub buffer: Buffer,
 }
 
 pub struct ImageReceiver {
-    pub receiver: Receiver<Vec<u8>>,
+    pub receiver: Receiver<(Vec<u8>, Instant)>,
+    /// Holds the last image data received, until it is taken with `take_received_image`. Its data
+    /// is empty when a frame has not been read back since the pipe was created or last taken.
     pub receiver_image: Image,
+    /// When the data in `receiver_image` was read back from the GPU.
+    pub captured_at: Instant,
 }
 
 pub fn create_image_pipe(
     images: &mut Assets<Image>,
     render_device: &RenderDevice,
     dimensions: (u32, u32),
+    format: TextureFormat,
 ) -> (ImageSender, ImageReceiver) {
     let (sender, receiver, buffer, sender_image, receiver_image) =
-        create_image_copy_objects(render_device, images, dimensions);
+        create_image_copy_objects(render_device, images, dimensions, format);
 
     let camera_sender = ImageSender {
         sender,
-        sender_image,
+        sender_image: Some(sender_image),
         buffer,
     };
 
     let camera_receiver = ImageReceiver {
         receiver,
         receiver_image,
+        captured_at: Instant::now(),
     };
 
     (camera_sender, camera_receiver)
 }
 
+/// Creates a pipe that reads back from the camera's view target instead of a sender image.
+pub fn create_view_mirror_pipe(
+    render_device: &RenderDevice,
+    dimensions: (u32, u32),
+    format: TextureFormat,
+) -> (ImageSender, ImageReceiver) {
+    let (sender, receiver) = crossbeam_channel::unbounded();
+    let (_, receiver_image) = create_image_copy_textures(dimensions, format);
+    let buffer = create_image_copy_buffer(render_device, dimensions, format);
+
+    let camera_sender = ImageSender {
+        sender,
+        sender_image: None,
+        buffer,
+    };
+
+    let camera_receiver = ImageReceiver {
+        receiver,
+        receiver_image,
+        captured_at: Instant::now(),
+    };
+
+    (camera_sender, camera_receiver)
+}
+
+/// Whether images of this format can be converted into terminal colors once read back.
+pub fn is_readback_format_supported(format: TextureFormat) -> bool {
+    matches!(
+        format,
+        TextureFormat::R8Unorm
+            | TextureFormat::Rg8Unorm
+            | TextureFormat::Rgba8UnormSrgb
+            | TextureFormat::Bgra8UnormSrgb
+            | TextureFormat::Bgra8Unorm
+            | TextureFormat::Rgba16Float
+    )
+}
+
+/// Creates a pipe that reads back from an existing image. Returns None if the image has not
+/// been loaded yet, or (with a warning) if its format cannot be read back.
+pub fn create_image_mirror_pipe(
+    images: &mut Assets<Image>,
+    render_device: &RenderDevice,
+    sender_image: &Handle<Image>,
+) -> Option<(ImageSender, ImageReceiver)> {
+    let image = images.get(sender_image)?;
+    let dimensions = (image.width(), image.height());
+    let format = image.texture_descriptor.format;
+
+    if !is_readback_format_supported(format) {
+        log::warn!("cannot mirror an image with texture format {format:?} to a ratatui camera");
+        return None;
+    }
+
+    if !image
+        .texture_descriptor
+        .usage
+        .contains(TextureUsages::COPY_SRC)
+    {
+        images.get_mut(sender_image)?.texture_descriptor.usage |= TextureUsages::COPY_SRC;
+    }
+
+    let (sender, receiver) = crossbeam_channel::unbounded();
+    let (_, receiver_image) = create_image_copy_textures(dimensions, format);
+    let buffer = create_image_copy_buffer(render_device, dimensions, format);
+
+    let camera_sender = ImageSender {
+        sender,
+        sender_image: Some(sender_image.clone()),
+        buffer,
+    };
+
+    let camera_receiver = ImageReceiver {
+        receiver,
+        receiver_image,
+        captured_at: Instant::now(),
+    };
+
+    Some((camera_sender, camera_receiver))
+}
+
 fn create_image_copy_objects(
     render_device: &RenderDevice,
     images: &mut Assets<Image>,
     dimensions: (u32, u32),
+    format: TextureFormat,
 ) -> (
-    Sender<Vec<u8>>,
-    Receiver<Vec<u8>>,
+    Sender<(Vec<u8>, Instant)>,
+    Receiver<(Vec<u8>, Instant)>,
     Buffer,
     Handle<Image>,
     Image,
 ) {
     let (sender, receiver) = crossbeam_channel::unbounded();
-    let (sender_texture, receiver_texture) = create_image_copy_textures(dimensions);
-    let buffer = create_image_copy_buffer(render_device, dimensions);
+    let (sender_texture, receiver_texture) = create_image_copy_textures(dimensions, format);
+    let buffer = create_image_copy_buffer(render_device, dimensions, format);
     let sender_handle = images.add(sender_texture);
 
     (sender, receiver, buffer, sender_handle, receiver_texture)
 }
 
-fn create_image_copy_textures(dimensions: (u32, u32)) -> (Image, Image) {
+fn create_image_copy_textures(dimensions: (u32, u32), format: TextureFormat) -> (Image, Image) {
     let (width, height) = dimensions;
     let size = Extent3d {
         width,
@@ -76,12 +166,13 @@ fn create_image_copy_textures(dimensions: (u32, u32)) -> (Image, Image) {
     let mut sender_texture = Image::new_fill(
         size,
         TextureDimension::D2,
-        &[0; 4],
-        TextureFormat::bevy_default(),
+        &vec![0; format.pixel_size()],
+        format,
         RenderAssetUsages::default(),
     );
 
-    let receiver_texture = sender_texture.clone();
+    let mut receiver_texture = sender_texture.clone();
+    receiver_texture.data = Vec::new();
 
     sender_texture.texture_descriptor.usage |=
         TextureUsages::COPY_SRC | TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING;
@@ -89,8 +180,13 @@ fn create_image_copy_textures(dimensions: (u32, u32)) -> (Image, Image) {
     (sender_texture, receiver_texture)
 }
 
-fn create_image_copy_buffer(render_device: &RenderDevice, (width, height): (u32, u32)) -> Buffer {
-    let padded_bytes_per_row = RenderDevice::align_copy_bytes_per_row((width) as usize) * 4;
+fn create_image_copy_buffer(
+    render_device: &RenderDevice,
+    (width, height): (u32, u32),
+    format: TextureFormat,
+) -> Buffer {
+    let padded_bytes_per_row =
+        RenderDevice::align_copy_bytes_per_row(width as usize * format.pixel_size());
     let buffer_descriptor = BufferDescriptor {
         label: None,
         size: padded_bytes_per_row as u64 * height as u64,
@@ -101,7 +197,11 @@ fn create_image_copy_buffer(render_device: &RenderDevice, (width, height): (u32,
     render_device.create_buffer(&buffer_descriptor)
 }
 
-pub fn send_image_buffer(render_device: &RenderDevice, buffer: &Buffer, sender: &Sender<Vec<u8>>) {
+pub fn send_image_buffer(
+    render_device: &RenderDevice,
+    buffer: &Buffer,
+    sender: &Sender<(Vec<u8>, Instant)>,
+) {
     let buffer_slice = buffer.slice(..);
 
     let (s, r) = crossbeam_channel::bounded(1);
@@ -115,36 +215,51 @@ pub fn send_image_buffer(render_device: &RenderDevice, buffer: &Buffer, sender:
 
     r.recv().expect("failed to receive the map_async message");
 
-    let _ = sender.send(buffer_slice.get_mapped_range().to_vec());
+    let _ = sender.send((buffer_slice.get_mapped_range().to_vec(), Instant::now()));
 
     buffer.unmap();
 }
 
-pub fn receive_image(image_receiver: &mut ImageReceiver) {
-    let mut image_data = Vec::new();
-    while let Ok(data) = image_receiver.receiver.try_recv() {
-        image_data = data;
-    }
+/// Returns the most recent image data sent through the pipe, if any arrived since last called.
+pub fn receive_image(image_receiver: &ImageReceiver) -> Option<(Vec<u8>, Instant)> {
+    image_receiver.receiver.try_iter().last()
+}
+
+/// Takes the last image data received (leaving the receiver image's data empty), as an image of the
+/// receiver image's dimensions and format. Returns None if no data was received since last taken.
+pub fn take_received_image(image_receiver: &mut ImageReceiver) -> Option<Image> {
+    let data = std::mem::take(&mut image_receiver.receiver_image.data);
 
-    if !image_data.is_empty() {
-        let row_bytes = image_receiver.receiver_image.width() as usize
-            * image_receiver
-                .receiver_image
-                .texture_descriptor
-                .format
-                .pixel_size();
-
-        let aligned_row_bytes = RenderDevice::align_copy_bytes_per_row(row_bytes);
-
-        if row_bytes == aligned_row_bytes {
-            image_receiver.receiver_image.data.clone_from(&image_data);
-        } else {
-            image_receiver.receiver_image.data = image_data
-                .chunks(aligned_row_bytes)
-                .take(image_receiver.receiver_image.height() as usize)
-                .flat_map(|row| &row[..row_bytes.min(row.len())])
-                .cloned()
-                .collect();
-        }
+    (!data.is_empty()).then(|| Image {
+        data,
+        ..image_receiver.receiver_image.clone()
+    })
+}
+
+/// Unpacks received image data into the receiver image, removing any row padding.
+pub fn update_receiver_image(
+    image_receiver: &mut ImageReceiver,
+    (image_data, captured_at): (Vec<u8>, Instant),
+) {
+    let row_bytes = image_receiver.receiver_image.width() as usize
+        * image_receiver
+            .receiver_image
+            .texture_descriptor
+            .format
+            .pixel_size();
+
+    let aligned_row_bytes = RenderDevice::align_copy_bytes_per_row(row_bytes);
+
+    if row_bytes == aligned_row_bytes {
+        image_receiver.receiver_image.data = image_data;
+    } else {
+        image_receiver.receiver_image.data = image_data
+            .chunks(aligned_row_bytes)
+            .take(image_receiver.receiver_image.height() as usize)
+            .flat_map(|row| &row[..row_bytes.min(row.len())])
+            .cloned()
+            .collect();
     }
+
+    image_receiver.captured_at = captured_at;
 }
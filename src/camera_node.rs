@@ -11,10 +11,11 @@ use bevy::{
             NodeRunError, RenderGraphApp, RenderGraphContext, RenderLabel, ViewNode, ViewNodeRunner,
         },
         render_resource::{
-            Buffer, CommandEncoderDescriptor, Extent3d, ImageCopyBuffer, ImageDataLayout,
+            Buffer, CommandEncoderDescriptor, Extent3d, ImageCopyBuffer, ImageDataLayout, Texture,
         },
         renderer::{RenderContext, RenderDevice, RenderQueue},
         texture::GpuImage,
+        view::ViewTarget,
         RenderApp,
     },
 };
@@ -45,6 +46,7 @@ pub struct RatatuiCameraLabel;
 
 impl ViewNode for RatatuiCameraNode {
     type ViewQuery = (
+        &'static ViewTarget,
         &'static RatatuiCameraSender,
         Option<&'static RatatuiSobelSender>,
     );
@@ -53,17 +55,34 @@ impl ViewNode for RatatuiCameraNode {
         &self,
         _graph: &mut RenderGraphContext,
         render_context: &mut RenderContext<'w>,
-        (camera_sender, sobel_sender): QueryItem<'w, Self::ViewQuery>,
+        (view_target, camera_sender, sobel_sender): QueryItem<'w, Self::ViewQuery>,
         world: &'w World,
     ) -> Result<(), NodeRunError> {
         let gpu_images = world.get_resource::<RenderAssets<GpuImage>>().unwrap();
 
-        let src_image = gpu_images.get(&camera_sender.sender_image).unwrap();
-        copy_to_buffer(render_context, world, src_image, &camera_sender.buffer);
+        let src_texture = match camera_sender.sender_image {
+            Some(ref sender_image) => gpu_images.get(sender_image).map(|image| &image.texture),
+            None => Some(view_target.main_texture()),
+        };
+
+        if let Some(src_texture) = src_texture {
+            copy_to_buffer(render_context, world, src_texture, &camera_sender.buffer);
+        }
 
         if let Some(sobel_sender) = sobel_sender {
-            let src_image_sobel = gpu_images.get(&sobel_sender.sender_image).unwrap();
-            copy_to_buffer(render_context, world, src_image_sobel, &sobel_sender.buffer);
+            let src_image_sobel = sobel_sender
+                .sender_image
+                .as_ref()
+                .and_then(|sender_image| gpu_images.get(sender_image));
+
+            if let Some(src_image_sobel) = src_image_sobel {
+                copy_to_buffer(
+                    render_context,
+                    world,
+                    &src_image_sobel.texture,
+                    &sobel_sender.buffer,
+                );
+            }
         }
 
         Ok(())
@@ -73,28 +92,34 @@ impl ViewNode for RatatuiCameraNode {
 fn copy_to_buffer(
     render_context: &mut RenderContext,
     world: &World,
-    src_image: &GpuImage,
+    src_texture: &Texture,
     buffer: &Buffer,
 ) {
     let mut encoder = render_context
         .render_device()
         .create_command_encoder(&CommandEncoderDescriptor::default());
 
-    let block_dimensions = src_image.texture_format.block_dimensions();
-    let block_size = src_image.texture_format.block_copy_size(None).unwrap();
+    let texture_format = src_texture.format();
+    let block_dimensions = texture_format.block_dimensions();
+    let block_size = texture_format.block_copy_size(None).unwrap();
 
     let padded_bytes_per_row = RenderDevice::align_copy_bytes_per_row(
-        (src_image.size.x as usize / block_dimensions.0 as usize) * block_size as usize,
+        (src_texture.width() as usize / block_dimensions.0 as usize) * block_size as usize,
     );
 
+    // mirrored targets can be resized before the buffer is recreated to match.
+    if padded_bytes_per_row as u64 * src_texture.height() as u64 != buffer.size() {
+        return;
+    }
+
     let texture_extent = Extent3d {
-        width: src_image.size.x,
-        height: src_image.size.y,
+        width: src_texture.width(),
+        height: src_texture.height(),
         depth_or_array_layers: 1,
     };
 
     encoder.copy_texture_to_buffer(
-        src_image.texture.as_image_copy(),
+        src_texture.as_image_copy(),
         ImageCopyBuffer {
             buffer,
             layout: ImageDataLayout {
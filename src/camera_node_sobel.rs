@@ -113,7 +113,13 @@ impl ViewNode for RatatuiCameraNodeSobel {
         };
 
         let source = view_target.main_texture_view();
-        let destination = gpu_images.get(&sobel_sender.sender_image).unwrap();
+        let Some(destination) = sobel_sender
+            .sender_image
+            .as_ref()
+            .and_then(|sender_image| gpu_images.get(sender_image))
+        else {
+            return Ok(());
+        };
         let view_uniforms = world.resource::<ViewUniforms>();
 
         let (Some(depth_prepass), Some(normal_prepass)) = (
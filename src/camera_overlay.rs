@@ -0,0 +1,381 @@
+use bevy::ecs::system::SystemParam;
+use bevy::picking::mesh_picking::ray_cast::{MeshRayCast, RayCastSettings};
+use bevy::prelude::*;
+use bevy::render::view::RenderLayers;
+use ratatui::style::{Color, Style};
+
+use crate::camera_readback::create_ratatui_camera_widgets_system;
+use crate::{RatatuiCamera, RatatuiCameraWidget};
+
+pub struct RatatuiCameraOverlayPlugin;
+
+impl Plugin for RatatuiCameraOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            First,
+            (project_ratatui_labels_system, project_ratatui_glyphs_system)
+                .after(create_ratatui_camera_widgets_system),
+        );
+    }
+}
+
+/// Insert this component into a scene entity to print native terminal text at the entity's
+/// position when a RatatuiCameraWidget that can see it is drawn. The entity's position is
+/// projected through each RatatuiCamera (respecting `RenderLayers` and visibility), and the text
+/// is centered on the resulting cell, drawn over the converted image.
+///
+/// Labels closer to the camera are drawn over labels that are farther away, and labels that would
+/// fall outside of the drawn image are clamped to its edges. Labels behind the camera are hidden,
+/// as are labels hidden behind meshes the camera can see (tested by casting a ray from the camera
+/// to the label); the meshes of the labelled entity itself and its descendants are ignored, so
+/// that a label inside its own mesh is still drawn.
+///
+#[derive(Component, Clone, Default)]
+pub struct RatatuiLabel {
+    /// The text to print.
+    pub text: String,
+
+    /// The ratatui style of the printed text.
+    pub style: Style,
+
+    /// Offset from the entity's position in world space, for example to print a name above a
+    /// character's head.
+    pub offset: Vec3,
+}
+
+impl RatatuiLabel {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..default()
+        }
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+/// Insert this component into a scene entity to draw it as a single terminal character at the
+/// cell its position projects to, composited over the converted image of each RatatuiCamera that
+/// can see it (respecting `RenderLayers` and visibility). Useful for roguelike-style actors that
+/// need to be an exact character, either alone or alongside a mesh or sprite.
+///
+/// When several glyphs land on the same cell, the one with the highest `priority` is drawn, and
+/// the nearest is drawn among glyphs with equal priority. Glyphs outside of the drawn image are
+/// not drawn.
+///
+#[derive(Component, Clone, Copy)]
+pub struct RatatuiGlyph {
+    /// The character to draw.
+    pub character: char,
+
+    /// The foreground color of the character. If `None`, the color of the cell is kept.
+    pub fg: Option<Color>,
+
+    /// The background color of the cell. If `None`, the color of the cell is kept.
+    pub bg: Option<Color>,
+
+    /// Glyphs with a higher priority are drawn over glyphs with a lower priority.
+    pub priority: i32,
+}
+
+impl Default for RatatuiGlyph {
+    fn default() -> Self {
+        Self {
+            character: '@',
+            fg: None,
+            bg: None,
+            priority: 0,
+        }
+    }
+}
+
+impl RatatuiGlyph {
+    pub fn new(character: char) -> Self {
+        Self {
+            character,
+            ..default()
+        }
+    }
+
+    pub fn with_fg(mut self, fg: Color) -> Self {
+        self.fg = Some(fg);
+        self
+    }
+
+    pub fn with_bg(mut self, bg: Color) -> Self {
+        self.bg = Some(bg);
+        self
+    }
+
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+/// A label projected through a camera, positioned in normalized image coordinates.
+#[derive(Clone, PartialEq)]
+pub struct ProjectedLabel {
+    pub text: String,
+    pub style: Style,
+    pub normalized: Vec2,
+    pub depth: f32,
+}
+
+/// A glyph projected through a camera, positioned in normalized image coordinates.
+#[derive(Clone, Copy, PartialEq)]
+pub struct ProjectedGlyph {
+    pub character: char,
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub normalized: Vec2,
+}
+
+/// Projects a world space position through a camera, returning its position in the rendered
+/// image normalized to 0.0-1.0, and its distance in front of the camera. Returns `None` for
+/// positions behind the camera.
+pub fn project_to_normalized(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    world_position: Vec3,
+) -> Option<(Vec2, f32)> {
+    let view_position = camera_transform
+        .affine()
+        .inverse()
+        .transform_point3(world_position);
+
+    let depth = -view_position.z;
+    if depth <= 0.0 {
+        return None;
+    }
+
+    let ndc = camera.world_to_ndc(camera_transform, world_position)?;
+    let normalized = Vec2::new((ndc.x + 1.) / 2., (1. - ndc.y) / 2.);
+
+    Some((normalized, depth))
+}
+
+/// Whether an entity with the given render layers and visibility can be seen by a camera with the
+/// given render layers.
+pub fn is_visible_to_camera(
+    camera_layers: Option<&RenderLayers>,
+    entity_layers: Option<&RenderLayers>,
+    visibility: Option<&InheritedVisibility>,
+) -> bool {
+    let default_layers = RenderLayers::default();
+    let camera_layers = camera_layers.unwrap_or(&default_layers);
+    let entity_layers = entity_layers.unwrap_or(&default_layers);
+
+    visibility.is_none_or(|visibility| visibility.get()) && camera_layers.intersects(entity_layers)
+}
+
+/// The distance in world units that a mesh must be in front of a label in order to hide it, so
+/// that labels placed on a surface are not hidden by it.
+const OCCLUSION_BIAS: f32 = 0.01;
+
+/// Tests whether world space positions are hidden from a camera behind meshes, by casting rays
+/// against the meshes the camera can see.
+#[derive(SystemParam)]
+struct MeshOcclusion<'w, 's> {
+    ray_cast: MeshRayCast<'w, 's>,
+    render_layers: Query<'w, 's, &'static RenderLayers>,
+    parents: Query<'w, 's, &'static Parent>,
+}
+
+impl MeshOcclusion<'_, '_> {
+    /// Whether a mesh visible to the camera lies between the camera and a world space position,
+    /// ignoring the meshes of `entity` and its descendants.
+    fn is_occluded(
+        &mut self,
+        camera: &Camera,
+        camera_transform: &GlobalTransform,
+        camera_layers: Option<&RenderLayers>,
+        entity: Entity,
+        world_position: Vec3,
+    ) -> bool {
+        // Cast from the point on the near plane that the position projects to, which works for
+        // both perspective and orthographic projections.
+        let Some(origin) = camera
+            .world_to_ndc(camera_transform, world_position)
+            .and_then(|ndc| camera.ndc_to_world(camera_transform, ndc.truncate().extend(1.0)))
+        else {
+            return false;
+        };
+
+        let offset = world_position - origin;
+        let Ok(direction) = Dir3::new(offset) else {
+            return false;
+        };
+
+        let (render_layers, parents) = (&self.render_layers, &self.parents);
+        let filter = |mesh_entity: Entity| {
+            mesh_entity != entity
+                && !parents
+                    .iter_ancestors(mesh_entity)
+                    .any(|ancestor| ancestor == entity)
+                && is_visible_to_camera(camera_layers, render_layers.get(mesh_entity).ok(), None)
+        };
+        let settings = RayCastSettings::default().with_filter(&filter);
+
+        self.ray_cast
+            .cast_ray(Ray3d::new(origin, direction), &settings)
+            .first()
+            .is_some_and(|(_, hit)| hit.distance < offset.length() - OCCLUSION_BIAS)
+    }
+}
+
+/// The query for entities drawn over camera widgets with an overlay component `T`.
+type OverlayQuery<'w, 's, T> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static T,
+        &'static GlobalTransform,
+        Option<&'static RenderLayers>,
+        Option<&'static InheritedVisibility>,
+    ),
+>;
+
+/// An entity with an overlay component, projected through a camera.
+struct OverlayProjection<'a, T> {
+    entity: Entity,
+    component: &'a T,
+    world_position: Vec3,
+    normalized: Vec2,
+    depth: f32,
+}
+
+/// Projects each entity in `entities` that the camera can see (at its position plus `offset`),
+/// collecting what `project` produces for the ones in front of the camera.
+fn project_overlay_entities<T: Component, P>(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    camera_layers: Option<&RenderLayers>,
+    entities: &OverlayQuery<T>,
+    offset: impl Fn(&T) -> Vec3,
+    mut project: impl FnMut(OverlayProjection<T>) -> Option<P>,
+) -> Vec<P> {
+    entities
+        .iter()
+        .filter(|(_, _, _, layers, visibility)| {
+            is_visible_to_camera(camera_layers, *layers, *visibility)
+        })
+        .filter_map(|(entity, component, transform, _, _)| {
+            let world_position = transform.translation() + offset(component);
+            let (normalized, depth) =
+                project_to_normalized(camera, camera_transform, world_position)?;
+
+            project(OverlayProjection {
+                entity,
+                component,
+                world_position,
+                normalized,
+                depth,
+            })
+        })
+        .collect()
+}
+
+fn project_ratatui_labels_system(
+    mut ratatui_cameras: Query<
+        (
+            &Camera,
+            &GlobalTransform,
+            Option<&RenderLayers>,
+            &mut RatatuiCameraWidget,
+        ),
+        With<RatatuiCamera>,
+    >,
+    labels: OverlayQuery<RatatuiLabel>,
+    mut occlusion: MeshOcclusion,
+) {
+    for (camera, camera_transform, camera_layers, mut widget) in &mut ratatui_cameras {
+        let mut projected_labels = project_overlay_entities(
+            camera,
+            camera_transform,
+            camera_layers,
+            &labels,
+            |label| label.offset,
+            |projection| {
+                let is_occluded = occlusion.is_occluded(
+                    camera,
+                    camera_transform,
+                    camera_layers,
+                    projection.entity,
+                    projection.world_position,
+                );
+
+                (!is_occluded).then(|| ProjectedLabel {
+                    text: projection.component.text.clone(),
+                    style: projection.component.style,
+                    normalized: projection.normalized,
+                    depth: projection.depth,
+                })
+            },
+        );
+
+        // Farthest first, so that nearer labels are drawn over them.
+        projected_labels.sort_by(|a, b| b.depth.total_cmp(&a.depth));
+
+        if widget.labels != projected_labels {
+            widget.labels = projected_labels;
+        }
+    }
+}
+
+fn project_ratatui_glyphs_system(
+    mut ratatui_cameras: Query<
+        (
+            &Camera,
+            &GlobalTransform,
+            Option<&RenderLayers>,
+            &mut RatatuiCameraWidget,
+        ),
+        With<RatatuiCamera>,
+    >,
+    glyphs: OverlayQuery<RatatuiGlyph>,
+) {
+    for (camera, camera_transform, camera_layers, mut widget) in &mut ratatui_cameras {
+        let mut projected_glyphs = project_overlay_entities(
+            camera,
+            camera_transform,
+            camera_layers,
+            &glyphs,
+            |_| Vec3::ZERO,
+            |projection| {
+                let glyph = projection.component;
+                let projected_glyph = ProjectedGlyph {
+                    character: glyph.character,
+                    fg: glyph.fg,
+                    bg: glyph.bg,
+                    normalized: projection.normalized,
+                };
+
+                Some((glyph.priority, projection.depth, projected_glyph))
+            },
+        );
+
+        // Lowest priority and farthest first, so that they are drawn over by the rest.
+        projected_glyphs.sort_by(|(priority_a, depth_a, _), (priority_b, depth_b, _)| {
+            priority_a.cmp(priority_b).then(depth_b.total_cmp(depth_a))
+        });
+
+        let projected_glyphs = projected_glyphs
+            .into_iter()
+            .map(|(_, _, projected_glyph)| projected_glyph)
+            .collect::<Vec<_>>();
+
+        if widget.glyphs != projected_glyphs {
+            widget.glyphs = projected_glyphs;
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use bevy::picking::pointer::{
+    Location, PointerAction, PointerButton, PointerId, PointerInput, PointerLocation,
+    PressDirection,
+};
+use bevy::picking::PickSet;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_ratatui::event::{InputSet, MouseEvent};
+use crossterm::event::{MouseButton, MouseEventKind};
+use uuid::Uuid;
+
+use crate::RatatuiCameraWidget;
+
+pub struct RatatuiCameraPickingPlugin;
+
+impl Plugin for RatatuiCameraPickingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<RatatuiCameraMouseEvent>().add_systems(
+            PreUpdate,
+            forward_ratatui_camera_mouse_events_system.after(InputSet::EmitCrossterm),
+        );
+    }
+}
+
+/// Sent for each terminal mouse event that lands on the image drawn by a RatatuiCameraWidget,
+/// translated into the world space of the camera that rendered it. Mouse capture must be enabled
+/// in the terminal (for example with bevy_ratatui's `MousePlugin`) for mouse events to be read.
+///
+/// The position of each widget is taken from the area it was last drawn in, so events are only
+/// sent for widgets that have been drawn at least once.
+///
+#[derive(Event, Clone, Debug)]
+pub struct RatatuiCameraMouseEvent {
+    /// The RatatuiCamera entity whose widget is under the mouse.
+    pub camera: Entity,
+
+    /// The kind of mouse event (click, release, drag, move, or scroll).
+    pub kind: MouseEventKind,
+
+    /// The terminal column of the mouse event.
+    pub column: u16,
+
+    /// The terminal row of the mouse event.
+    pub row: u16,
+
+    /// The position of the mouse event in the camera's viewport, in logical pixels.
+    pub viewport_position: Vec2,
+
+    /// The ray in world space from the camera through the scene under the mouse.
+    pub ray: Ray3d,
+}
+
+fn forward_ratatui_camera_mouse_events_system(
+    mut mouse_events: EventReader<MouseEvent>,
+    mut camera_mouse_events: EventWriter<RatatuiCameraMouseEvent>,
+    ratatui_cameras: Query<(Entity, &Camera, &GlobalTransform, &RatatuiCameraWidget)>,
+) {
+    for MouseEvent(mouse_event) in mouse_events.read() {
+        for (entity_id, camera, camera_transform, widget) in &ratatui_cameras {
+            let Some(viewport_position) =
+                widget.cell_to_viewport(mouse_event.column, mouse_event.row, camera)
+            else {
+                continue;
+            };
+
+            let Ok(ray) = camera.viewport_to_world(camera_transform, viewport_position) else {
+                continue;
+            };
+
+            camera_mouse_events.send(RatatuiCameraMouseEvent {
+                camera: entity_id,
+                kind: mouse_event.kind,
+                column: mouse_event.column,
+                row: mouse_event.row,
+                viewport_position,
+                ray,
+            });
+        }
+    }
+}
+
+/// The `PointerId` of the pointer driven by terminal mouse events when the
+/// RatatuiCameraPointerPlugin is added.
+pub const RATATUI_POINTER_ID: PointerId =
+    PointerId::Custom(Uuid::from_u128(0x5d6b_1f3e_8a2c_4e07_9c41_2b7d_a0e6_f813));
+
+/// Add this plugin to drive a `bevy_picking` pointer with terminal mouse events. When the mouse
+/// is over a drawn RatatuiCameraWidget, the pointer is located on the render target of the
+/// corresponding RatatuiCamera, so picking backends (like the mesh and sprite backends) will
+/// detect hits in the scene, and standard `Pointer<Click>`, `Pointer<Over>`, etc. events and
+/// observers will work as they do with a window's mouse pointer.
+///
+/// Mouse capture must be enabled in the terminal (for example with bevy_ratatui's `MousePlugin`),
+/// and the `PointerId` of the spawned pointer is `RATATUI_POINTER_ID`.
+///
+pub struct RatatuiCameraPointerPlugin;
+
+impl Plugin for RatatuiCameraPointerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_ratatui_pointer_system)
+            .add_systems(
+                PreUpdate,
+                ratatui_pointer_input_system
+                    .after(InputSet::EmitCrossterm)
+                    .before(PickSet::ProcessInput),
+            );
+    }
+}
+
+fn spawn_ratatui_pointer_system(mut commands: Commands) {
+    commands.spawn(RATATUI_POINTER_ID);
+}
+
+fn ratatui_pointer_input_system(
+    mut mouse_events: EventReader<MouseEvent>,
+    mut pointer_inputs: EventWriter<PointerInput>,
+    mut pointers: Query<(&PointerId, &mut PointerLocation)>,
+    ratatui_cameras: Query<(&Camera, &RatatuiCameraWidget)>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    mut last_location: Local<Option<Location>>,
+) {
+    for MouseEvent(mouse_event) in mouse_events.read() {
+        let location = ratatui_cameras
+            .iter()
+            .filter_map(|(camera, widget)| {
+                let normalized = widget.cell_to_normalized(mouse_event.column, mouse_event.row)?;
+                let viewport_rect = camera.logical_viewport_rect()?;
+                let target = camera.target.normalize(primary_window.get_single().ok())?;
+
+                let location = Location {
+                    target,
+                    position: viewport_rect.min + normalized * viewport_rect.size(),
+                };
+
+                Some((camera.order, location))
+            })
+            .max_by_key(|(order, _)| *order)
+            .map(|(_, location)| location);
+
+        let button = match mouse_event.kind {
+            MouseEventKind::Down(button) | MouseEventKind::Up(button) => match button {
+                MouseButton::Left => Some(PointerButton::Primary),
+                MouseButton::Right => Some(PointerButton::Secondary),
+                MouseButton::Middle => Some(PointerButton::Middle),
+            },
+            _ => None,
+        };
+
+        // Buttons released away from any widget are still released at the last location, so
+        // that presses do not get stuck.
+        if let (MouseEventKind::Up(_), Some(button), None) = (mouse_event.kind, button, &location) {
+            if let Some(last_location) = last_location.as_ref() {
+                pointer_inputs.send(PointerInput::new(
+                    RATATUI_POINTER_ID,
+                    last_location.clone(),
+                    PointerAction::Pressed {
+                        direction: PressDirection::Up,
+                        button,
+                    },
+                ));
+            }
+        }
+
+        let Some(location) = location else {
+            if last_location.take().is_some() {
+                for (pointer_id, mut pointer_location) in &mut pointers {
+                    if *pointer_id == RATATUI_POINTER_ID {
+                        pointer_location.location = None;
+                    }
+                }
+            }
+            continue;
+        };
+
+        if last_location.as_ref() != Some(&location) {
+            let delta = last_location
+                .as_ref()
+                .filter(|last_location| last_location.target == location.target)
+                .map(|last_location| location.position - last_location.position)
+                .unwrap_or_default();
+
+            pointer_inputs.send(PointerInput::new(
+                RATATUI_POINTER_ID,
+                location.clone(),
+                PointerAction::Moved { delta },
+            ));
+        }
+
+        if let Some(button) = button {
+            let direction = match mouse_event.kind {
+                MouseEventKind::Down(_) => PressDirection::Down,
+                _ => PressDirection::Up,
+            };
+
+            pointer_inputs.send(PointerInput::new(
+                RATATUI_POINTER_ID,
+                location.clone(),
+                PointerAction::Pressed { direction, button },
+            ));
+        }
+
+        *last_location = Some(location);
+    }
+}
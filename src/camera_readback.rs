@@ -1,23 +1,35 @@
+use std::{sync::Arc, time::Duration};
+
 use bevy::{
     core_pipeline::prepass::{DepthPrepass, NormalPrepass},
+    ecs::{query::QueryItem, system::SystemState},
     prelude::*,
     render::{
         camera::RenderTarget,
         extract_component::{ExtractComponent, ExtractComponentPlugin},
+        render_resource::TextureFormat,
         renderer::RenderDevice,
         Render, RenderApp, RenderSet,
     },
+    utils::HashMap,
 };
 use bevy_ratatui::{event::ResizeEvent, terminal::RatatuiContext};
+use image::DynamicImage;
 
 use crate::{
     camera_image_pipe::{
-        create_image_pipe, receive_image, send_image_buffer, ImageReceiver, ImageSender,
+        create_image_mirror_pipe, create_image_pipe, create_view_mirror_pipe, receive_image,
+        send_image_buffer, take_received_image, update_receiver_image, ImageReceiver, ImageSender,
     },
-    RatatuiCamera, RatatuiCameraEdgeDetection, RatatuiCameraStrategy, RatatuiCameraWidget,
+    RatatuiCamera, RatatuiCameraEdgeDetection, RatatuiCameraLayout, RatatuiCameraRedraw,
+    RatatuiCameraRenderPolicy, RatatuiCameraSource, RatatuiCameraStrategy,
+    RatatuiCameraTonemapping, RatatuiCameraTransparency, RatatuiCameraViewport,
+    RatatuiCameraWidget,
 };
 
-pub struct RatatuiCameraReadbackPlugin;
+pub struct RatatuiCameraReadbackPlugin {
+    pub headless: bool,
+}
 
 impl Plugin for RatatuiCameraReadbackPlugin {
     fn build(&self, app: &mut App) {
@@ -25,15 +37,16 @@ impl Plugin for RatatuiCameraReadbackPlugin {
             ExtractComponentPlugin::<RatatuiCameraSender>::default(),
             ExtractComponentPlugin::<RatatuiSobelSender>::default(),
         ))
+        .add_event::<RatatuiCameraRedraw>()
         .add_observer(handle_ratatui_camera_insert_system)
         .add_observer(handle_ratatui_camera_removal_system)
         .add_observer(handle_ratatui_edge_detection_insert_system)
         .add_observer(handle_ratatui_edge_detection_removal_system)
-        .add_systems(PostStartup, initial_autoresize_system)
         .add_systems(
             First,
             (
-                autoresize_ratatui_camera_system,
+                restore_ratatui_camera_activity_system,
+                mirror_ratatui_camera_dimensions_system,
                 (
                     update_ratatui_camera_readback_system,
                     update_ratatui_edge_detection_readback_system,
@@ -43,8 +56,23 @@ impl Plugin for RatatuiCameraReadbackPlugin {
                 create_ratatui_camera_widgets_system,
             )
                 .chain(),
+        )
+        .add_systems(
+            PostUpdate,
+            update_ratatui_camera_render_policy_system.after(TransformSystem::TransformPropagate),
         );
 
+        if !self.headless {
+            app.add_systems(PostStartup, initial_autoresize_system)
+                .add_systems(
+                    First,
+                    autoresize_ratatui_camera_system
+                        .after(mirror_ratatui_camera_dimensions_system)
+                        .before(update_ratatui_camera_readback_system)
+                        .before(update_ratatui_edge_detection_readback_system),
+                );
+        }
+
         let render_app = app.sub_app_mut(RenderApp);
         render_app.add_systems(
             Render,
@@ -53,18 +81,70 @@ impl Plugin for RatatuiCameraReadbackPlugin {
     }
 }
 
-#[derive(Component, ExtractComponent, Clone, Deref, DerefMut)]
+#[derive(Component, Clone, Deref, DerefMut)]
 pub struct RatatuiCameraSender(ImageSender);
 
 #[derive(Component, Deref, DerefMut)]
 pub struct RatatuiCameraReceiver(ImageReceiver);
 
-#[derive(Component, ExtractComponent, Clone, Deref, DerefMut)]
+#[derive(Component, Clone, Deref, DerefMut)]
 pub struct RatatuiSobelSender(ImageSender);
 
 #[derive(Component, Deref, DerefMut)]
 pub struct RatatuiSobelReceiver(ImageReceiver);
 
+/// Tracks how a RatatuiCamera's render policy is being applied.
+#[derive(Component, Default)]
+pub struct RatatuiCameraRenderState {
+    /// Time (since startup) of the last frame the camera rendered a new frame for.
+    last_render: Option<Duration>,
+
+    /// Whether the camera is idle this frame, in which case its readback is skipped.
+    idle: bool,
+
+    /// Whether the camera was made inactive by its render policy (rather than by the user), and
+    /// needs to be made active again at the start of the next frame.
+    deactivated: bool,
+}
+
+/// Senders are only extracted for active cameras that are not idle, so that idle cameras skip
+/// their readback.
+impl ExtractComponent for RatatuiCameraSender {
+    type QueryData = (
+        &'static Self,
+        &'static Camera,
+        Option<&'static RatatuiCameraRenderState>,
+    );
+    type QueryFilter = ();
+    type Out = Self;
+
+    fn extract_component(
+        (sender, camera, render_state): QueryItem<'_, Self::QueryData>,
+    ) -> Option<Self::Out> {
+        should_read_back(camera, render_state).then(|| sender.clone())
+    }
+}
+
+impl ExtractComponent for RatatuiSobelSender {
+    type QueryData = (
+        &'static Self,
+        &'static Camera,
+        Option<&'static RatatuiCameraRenderState>,
+    );
+    type QueryFilter = ();
+    type Out = Self;
+
+    fn extract_component(
+        (sender, camera, render_state): QueryItem<'_, Self::QueryData>,
+    ) -> Option<Self::Out> {
+        should_read_back(camera, render_state).then(|| sender.clone())
+    }
+}
+
+fn should_read_back(camera: &Camera, render_state: Option<&RatatuiCameraRenderState>) -> bool {
+    camera.is_active && render_state.is_none_or(|render_state| !render_state.idle)
+}
+
 fn handle_ratatui_camera_insert_system(
     trigger: Trigger<OnInsert, RatatuiCamera>,
     mut commands: Commands,
@@ -87,9 +167,20 @@ fn handle_ratatui_camera_insert_system(
 fn handle_ratatui_camera_removal_system(
     trigger: Trigger<OnRemove, RatatuiCamera>,
     mut commands: Commands,
+    mut ratatui_cameras: Query<(&mut Camera, &RatatuiCameraRenderState)>,
 ) {
+    if let Ok((mut camera, render_state)) = ratatui_cameras.get_mut(trigger.entity()) {
+        if render_state.deactivated {
+            camera.bypass_change_detection().is_active = true;
+        }
+    }
+
     let mut entity = commands.entity(trigger.entity());
-    entity.remove::<(RatatuiCameraSender, RatatuiCameraReceiver)>();
+    entity.remove::<(
+        RatatuiCameraSender,
+        RatatuiCameraReceiver,
+        RatatuiCameraRenderState,
+    )>();
 }
 
 fn handle_ratatui_edge_detection_insert_system(
@@ -118,19 +209,58 @@ fn handle_ratatui_edge_detection_removal_system(
     entity.remove::<(RatatuiSobelSender, RatatuiSobelReceiver)>();
 }
 
+/// Recreates the readback pipe of each camera whose RatatuiCamera changed, whose texture format
+/// changed (when toggling HDR), or whose mirrored image has just been loaded.
 fn update_ratatui_camera_readback_system(
     mut commands: Commands,
-    mut ratatui_cameras: Query<(Entity, &mut Camera, &RatatuiCamera), Changed<RatatuiCamera>>,
+    mut image_events: EventReader<AssetEvent<Image>>,
+    mut ratatui_cameras: Query<
+        (
+            Entity,
+            &mut Camera,
+            Ref<RatatuiCamera>,
+            Option<&RatatuiCameraReceiver>,
+        ),
+        Or<(
+            Changed<RatatuiCamera>,
+            Changed<Camera>,
+            Without<RatatuiCameraReceiver>,
+        )>,
+    >,
     mut image_assets: ResMut<Assets<Image>>,
     render_device: Res<RenderDevice>,
 ) {
-    for (entity, mut camera, ratatui_camera) in &mut ratatui_cameras {
+    let loaded_images: Vec<AssetId<Image>> = image_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Added { id } | AssetEvent::LoadedWithDependencies { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+
+    for (entity, mut camera, ratatui_camera, camera_receiver) in &mut ratatui_cameras {
+        let needs_update = ratatui_camera.is_changed()
+            || match (&ratatui_camera.source, camera_receiver) {
+                (RatatuiCameraSource::MirrorImage(sender_image), None) => {
+                    loaded_images.contains(&sender_image.id())
+                }
+                (RatatuiCameraSource::MirrorImage(_), Some(_)) | (_, None) => false,
+                (_, Some(camera_receiver)) => {
+                    camera_receiver.receiver_image.texture_descriptor.format
+                        != camera_texture_format(&camera)
+                }
+            };
+
+        if !needs_update {
+            continue;
+        }
+
         insert_camera_readback_components(
             &mut commands,
             entity,
             &mut image_assets,
             &render_device,
-            ratatui_camera,
+            &ratatui_camera,
             &mut camera,
         );
     }
@@ -176,52 +306,253 @@ fn send_sobel_images_system(
 
 fn receive_camera_images_system(mut camera_receivers: Query<&mut RatatuiCameraReceiver>) {
     for mut camera_receiver in &mut camera_receivers {
-        receive_image(&mut camera_receiver);
+        if let Some(image_data) = receive_image(&camera_receiver) {
+            update_receiver_image(&mut camera_receiver, image_data);
+        }
     }
 }
 
 fn receive_sobel_images_system(mut sobel_receivers: Query<&mut RatatuiSobelReceiver>) {
     for mut sobel_receiver in &mut sobel_receivers {
-        receive_image(&mut sobel_receiver);
+        if let Some(image_data) = receive_image(&sobel_receiver) {
+            update_receiver_image(&mut sobel_receiver, image_data);
+        }
     }
 }
 
-fn create_ratatui_camera_widgets_system(
+/// Inserts or updates each camera's widget, only replacing its frames when new data was received.
+pub(crate) fn create_ratatui_camera_widgets_system(
     mut commands: Commands,
-    ratatui_cameras: Query<
+    mut ratatui_cameras: Query<
         (
             Entity,
             &RatatuiCameraStrategy,
             Option<&RatatuiCameraEdgeDetection>,
-            &RatatuiCameraReceiver,
-            Option<&RatatuiSobelReceiver>,
+            Option<&RatatuiCameraLayout>,
+            Option<&RatatuiCameraTransparency>,
+            Option<&RatatuiCameraViewport>,
+            Option<&RatatuiCameraTonemapping>,
+            &mut RatatuiCameraReceiver,
+            Option<&mut RatatuiSobelReceiver>,
+            Option<&mut RatatuiCameraWidget>,
         ),
         With<RatatuiCamera>,
     >,
 ) {
-    for (entity_id, strategy, edge_detection, camera_receiver, sobel_receiver) in &ratatui_cameras {
-        let mut entity = commands.entity(entity_id);
-
-        let camera_image = match camera_receiver.receiver_image.clone().try_into_dynamic() {
-            Ok(image) => image,
-            Err(e) => panic!("failed to create camera image buffer {e:?}"),
+    for (
+        entity_id,
+        strategy,
+        edge_detection,
+        layout,
+        transparency,
+        viewport,
+        tonemapping,
+        mut camera_receiver,
+        sobel_receiver,
+        widget,
+    ) in &mut ratatui_cameras
+    {
+        let Some(mut widget) = widget else {
+            // the widget is only created once the first frame has been read back.
+            let Some(camera_image) = convert_camera_image(&mut camera_receiver, tonemapping) else {
+                continue;
+            };
+
+            let widget = RatatuiCameraWidget {
+                camera_image: Arc::new(camera_image),
+                sobel_image: sobel_receiver
+                    .and_then(|mut sobel_receiver| convert_sobel_image(&mut sobel_receiver))
+                    .map(Arc::new),
+                strategy: strategy.clone(),
+                edge_detection: edge_detection.cloned(),
+                layout: layout.copied().unwrap_or_default(),
+                transparency: transparency.copied().unwrap_or_default(),
+                viewport: viewport.copied().unwrap_or_default(),
+                frame: 0,
+                captured_at: camera_receiver.captured_at,
+                last_area: default(),
+                labels: Vec::new(),
+                glyphs: Vec::new(),
+                cells: None,
+            };
+
+            commands.entity(entity_id).insert(widget);
+            continue;
         };
 
-        let sobel_image = sobel_receiver.as_ref().map(|image_sobel| {
-            match image_sobel.receiver_image.clone().try_into_dynamic() {
-                Ok(image) => image,
-                Err(e) => panic!("failed to create sobel image buffer {e:?}"),
+        if camera_receiver.is_changed() {
+            if let Some(camera_image) = convert_camera_image(&mut camera_receiver, tonemapping) {
+                widget.camera_image = Arc::new(camera_image);
+                widget.captured_at = camera_receiver.captured_at;
+                widget.frame += 1;
             }
-        });
+        }
+
+        match sobel_receiver {
+            Some(mut sobel_receiver) if sobel_receiver.is_changed() => {
+                if let Some(sobel_image) = convert_sobel_image(&mut sobel_receiver) {
+                    widget.sobel_image = Some(Arc::new(sobel_image));
+                }
+            }
+            None if widget.sobel_image.is_some() => {
+                widget.sobel_image = None;
+            }
+            _ => {}
+        }
+
+        if widget.strategy != *strategy {
+            widget.strategy = strategy.clone();
+        }
+
+        if widget.edge_detection.as_ref() != edge_detection {
+            widget.edge_detection = edge_detection.cloned();
+        }
+
+        let layout = layout.copied().unwrap_or_default();
+        if widget.layout != layout {
+            widget.layout = layout;
+        }
+
+        let transparency = transparency.copied().unwrap_or_default();
+        if widget.transparency != transparency {
+            widget.transparency = transparency;
+        }
+
+        let viewport = viewport.copied().unwrap_or_default();
+        if widget.viewport != viewport {
+            widget.viewport = viewport;
+        }
+    }
+}
+
+/// Converts the last frame read back into the receiver, moving its data out of the receiver rather
+/// than copying it. Returns None if no new frame has been read back since the last conversion.
+fn convert_camera_image(
+    camera_receiver: &mut Mut<RatatuiCameraReceiver>,
+    tonemapping: Option<&RatatuiCameraTonemapping>,
+) -> Option<DynamicImage> {
+    let camera_image = take_received_image(camera_receiver.bypass_change_detection())?;
+
+    if camera_image.texture_descriptor.format == TextureFormat::Rgba16Float {
+        Some(
+            tonemapping
+                .copied()
+                .unwrap_or_default()
+                .tonemap_image(&camera_image),
+        )
+    } else {
+        camera_image
+            .try_into_dynamic()
+            .inspect_err(|e| log::error!("failed to create camera image buffer: {e:?}"))
+            .ok()
+    }
+}
 
-        let widget = RatatuiCameraWidget {
-            camera_image,
-            sobel_image,
-            strategy: strategy.clone(),
-            edge_detection: edge_detection.cloned(),
+fn convert_sobel_image(sobel_receiver: &mut Mut<RatatuiSobelReceiver>) -> Option<DynamicImage> {
+    take_received_image(sobel_receiver.bypass_change_detection())?
+        .try_into_dynamic()
+        .inspect_err(|e| log::error!("failed to create sobel image buffer: {e:?}"))
+        .ok()
+}
+
+/// Marks each RatatuiCamera as idle or not according to its render policy. Idle cameras skip their
+/// readback, and idle cameras that own their render target are also made inactive for the frame
+/// (mirroring cameras keep rendering to their own target, such as a window). Cameras made inactive
+/// by the user are left alone, and only cameras with a throttled or on-demand policy are changed.
+fn update_ratatui_camera_render_policy_system(
+    time: Res<Time<Real>>,
+    mut redraw_events: EventReader<RatatuiCameraRedraw>,
+    mut mesh_events: EventReader<AssetEvent<Mesh>>,
+    scene_changes: Query<(), Or<(Changed<GlobalTransform>, Changed<Mesh3d>, Changed<Mesh2d>)>>,
+    mut ratatui_cameras: Query<(
+        Entity,
+        &mut Camera,
+        Ref<RatatuiCamera>,
+        &mut RatatuiCameraRenderState,
+    )>,
+) {
+    let redraws = redraw_events
+        .read()
+        .map(|event| event.camera)
+        .collect::<Vec<_>>();
+    let scene_changed = mesh_events.read().count() > 0 || !scene_changes.is_empty();
+
+    for (entity, mut camera, ratatui_camera, mut render_state) in &mut ratatui_cameras {
+        if !camera.is_active {
+            continue;
+        }
+
+        let last_render = render_state.last_render;
+        let should_render = ratatui_camera.is_changed()
+            || match ratatui_camera.render_policy {
+                RatatuiCameraRenderPolicy::EveryFrame => true,
+                RatatuiCameraRenderPolicy::MaxFps(_) => {
+                    let frame_interval = ratatui_camera.render_policy.frame_interval();
+                    last_render.is_none_or(|last_render| {
+                        frame_interval.is_some_and(|frame_interval| {
+                            time.elapsed() - last_render >= frame_interval
+                        })
+                    })
+                }
+                RatatuiCameraRenderPolicy::OnDemand { detect_changes } => {
+                    last_render.is_none()
+                        || (detect_changes && scene_changed)
+                        || redraws
+                            .iter()
+                            .any(|redraw| redraw.is_none_or(|camera| camera == entity))
+                }
+            };
+
+        if should_render {
+            render_state.last_render = Some(time.elapsed());
+        }
+
+        render_state.idle = !should_render;
+
+        // Toggling activity for the frame is not a change to the camera, and would otherwise
+        // trigger anything watching it for changes (such as the readback) twice per frame.
+        if !should_render && matches!(ratatui_camera.source, RatatuiCameraSource::Owned) {
+            camera.bypass_change_detection().is_active = false;
+            render_state.deactivated = true;
+        }
+    }
+}
+
+/// Makes cameras that were made inactive by their render policy active again, so that systems see
+/// the camera's own activity (and so that the render policy can decide again). Neither toggle marks
+/// the camera as changed.
+fn restore_ratatui_camera_activity_system(
+    mut ratatui_cameras: Query<(&mut Camera, &mut RatatuiCameraRenderState)>,
+) {
+    for (mut camera, mut render_state) in &mut ratatui_cameras {
+        if render_state.deactivated {
+            camera.bypass_change_detection().is_active = true;
+            render_state.deactivated = false;
+        }
+
+        render_state.idle = false;
+    }
+}
+
+/// Keeps the dimensions of mirroring cameras in sync with the size of what they mirror.
+fn mirror_ratatui_camera_dimensions_system(
+    mut ratatui_cameras: Query<(&Camera, &mut RatatuiCamera)>,
+    image_assets: Res<Assets<Image>>,
+) {
+    for (camera, mut ratatui_camera) in &mut ratatui_cameras {
+        let size = match ratatui_camera.source {
+            RatatuiCameraSource::Owned => continue,
+            RatatuiCameraSource::MirrorView => camera.physical_target_size(),
+            RatatuiCameraSource::MirrorImage(ref sender_image) => {
+                image_assets.get(sender_image).map(|image| image.size())
+            }
         };
 
-        entity.insert(widget);
+        if let Some(size) = size {
+            if ratatui_camera.dimensions != (size.x, size.y) {
+                ratatui_camera.dimensions = (size.x, size.y);
+            }
+        }
     }
 }
 
@@ -235,16 +566,65 @@ fn initial_autoresize_system(
     }
 }
 
-/// Autoresizes the send/receive textures to fit the terminal dimensions.
+/// Autoresizes the send/receive textures according to each camera's autoresize policy, using the
+/// area its widget was last drawn in (or the whole terminal, before the widget has been drawn).
+/// Policies are reapplied whenever that area or the camera changes.
 fn autoresize_ratatui_camera_system(
-    mut ratatui_cameras: Query<&mut RatatuiCamera>,
-    mut resize_events: EventReader<ResizeEvent>,
+    world: &mut World,
+    params: &mut SystemState<(
+        EventReader<ResizeEvent>,
+        Query<(Entity, Ref<RatatuiCamera>, Option<&RatatuiCameraWidget>)>,
+    )>,
+    mut terminal_dimensions: Local<Option<(u32, u32)>>,
+    mut last_available_dimensions: Local<HashMap<Entity, (u32, u32)>>,
 ) {
+    let (mut resize_events, ratatui_cameras) = params.get_mut(world);
+
     if let Some(ResizeEvent(dimensions)) = resize_events.read().last() {
-        for mut ratatui_camera in &mut ratatui_cameras {
-            if ratatui_camera.autoresize {
-                let terminal_dimensions = (dimensions.width as u32, dimensions.height as u32 * 2);
-                let new_dimensions = (ratatui_camera.autoresize_fn)(terminal_dimensions);
+        *terminal_dimensions = Some((dimensions.width as u32, dimensions.height as u32 * 2));
+    }
+
+    let mut autoresize_policies = Vec::new();
+
+    for (entity, ratatui_camera, widget) in &ratatui_cameras {
+        if !ratatui_camera.autoresize
+            || !matches!(ratatui_camera.source, RatatuiCameraSource::Owned)
+        {
+            continue;
+        }
+
+        let widget_dimensions = widget
+            .and_then(RatatuiCameraWidget::last_area)
+            .filter(|area| !area.is_empty())
+            .map(|area| (area.width as u32, area.height as u32 * 2));
+
+        let Some(available_dimensions) = widget_dimensions.or(*terminal_dimensions) else {
+            continue;
+        };
+
+        if last_available_dimensions.get(&entity) == Some(&available_dimensions)
+            && !ratatui_camera.is_changed()
+        {
+            continue;
+        }
+
+        last_available_dimensions.insert(entity, available_dimensions);
+        autoresize_policies.push((
+            entity,
+            available_dimensions,
+            ratatui_camera.autoresize_policy.clone(),
+        ));
+    }
+
+    last_available_dimensions.retain(|entity, _| ratatui_cameras.contains(*entity));
+
+    for (entity, available_dimensions, autoresize_policy) in autoresize_policies {
+        let Some(new_dimensions) = autoresize_policy.resize(available_dimensions, world) else {
+            continue;
+        };
+
+        if let Some(mut ratatui_camera) = world.get_mut::<RatatuiCamera>(entity) {
+            if ratatui_camera.dimensions != new_dimensions {
                 ratatui_camera.dimensions = new_dimensions;
             }
         }
@@ -259,14 +639,43 @@ fn insert_camera_readback_components(
     ratatui_camera: &RatatuiCamera,
     camera: &mut Camera,
 ) {
-    let mut entity = commands.entity(entity);
+    let (sender, receiver) = match ratatui_camera.source {
+        RatatuiCameraSource::Owned => {
+            let (sender, receiver) = create_image_pipe(
+                image_assets,
+                render_device,
+                ratatui_camera.dimensions,
+                camera_texture_format(camera),
+            );
+
+            if let Some(ref sender_image) = sender.sender_image {
+                camera.target = RenderTarget::from(sender_image.clone());
+            }
+
+            (sender, receiver)
+        }
+        RatatuiCameraSource::MirrorView => create_view_mirror_pipe(
+            render_device,
+            ratatui_camera.dimensions,
+            camera_texture_format(camera),
+        ),
+        RatatuiCameraSource::MirrorImage(ref sender_image) => {
+            let Some(pipe) = create_image_mirror_pipe(image_assets, render_device, sender_image)
+            else {
+                return;
+            };
 
-    let (sender, receiver) =
-        create_image_pipe(image_assets, render_device, ratatui_camera.dimensions);
+            pipe
+        }
+    };
 
-    camera.target = RenderTarget::from(sender.sender_image.clone());
+    let mut entity = commands.entity(entity);
 
-    entity.insert((RatatuiCameraSender(sender), RatatuiCameraReceiver(receiver)));
+    entity.insert((
+        RatatuiCameraSender(sender),
+        RatatuiCameraReceiver(receiver),
+        RatatuiCameraRenderState::default(),
+    ));
 }
 
 fn insert_edge_detection_readback_components(
@@ -278,8 +687,12 @@ fn insert_edge_detection_readback_components(
 ) {
     let mut entity = commands.entity(entity);
 
-    let (sender, receiver) =
-        create_image_pipe(image_assets, render_device, ratatui_camera.dimensions);
+    let (sender, receiver) = create_image_pipe(
+        image_assets,
+        render_device,
+        ratatui_camera.dimensions,
+        TextureFormat::bevy_default(),
+    );
 
     entity.insert((
         RatatuiSobelSender(sender),
@@ -289,3 +702,12 @@ fn insert_edge_detection_readback_components(
         Msaa::Off,
     ));
 }
+
+/// HDR cameras render to a floating point texture so that tonemapping can happen when unpacking.
+fn camera_texture_format(camera: &Camera) -> TextureFormat {
+    if camera.hdr {
+        TextureFormat::Rgba16Float
+    } else {
+        TextureFormat::bevy_default()
+    }
+}
@@ -0,0 +1,123 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::camera_cells::RatatuiCameraSet;
+use crate::{AsciicastWriter, RatatuiCamera, RatatuiCameraCells, RatatuiCameraRecordingWriter};
+
+pub struct RatatuiCameraRecorderPlugin;
+
+impl Plugin for RatatuiCameraRecorderPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PreUpdate,
+            record_ratatui_cameras_system.after(RatatuiCameraSet::PostProcess),
+        );
+    }
+}
+
+/// Insert this component alongside your `RatatuiCamera` to record its converted (and
+/// post-processed) cells every frame, timed by real elapsed time since recording started. Cells
+/// are recorded to an asciicast v2 file (refer to `AsciicastWriter`), or to the crate's own
+/// lossless format that can be replayed with a RatatuiCameraPlayer (refer to
+/// `RatatuiCameraRecording`).
+///
+/// Recording starts once the RatatuiCameraWidget has been drawn, and stops (with an error logged)
+/// if writing fails. Remove the component to finish the recording.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiCamera, RatatuiCameraRecorder};
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.spawn((
+///     Camera3d::default(),
+///     RatatuiCamera::default(),
+///     RatatuiCameraRecorder::create("recording.cast").unwrap(),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component)]
+pub struct RatatuiCameraRecorder {
+    writer: Option<RecorderWriter>,
+    started_at: Option<Duration>,
+}
+
+type BoxedWriter = Box<dyn Write + Send + Sync>;
+
+enum RecorderWriter {
+    Asciicast(AsciicastWriter<BoxedWriter>),
+    Recording(RatatuiCameraRecordingWriter<BoxedWriter>),
+}
+
+impl RecorderWriter {
+    fn write_cells(&mut self, time: Duration, cells: &RatatuiCameraCells) -> io::Result<()> {
+        match self {
+            RecorderWriter::Asciicast(writer) => writer.write_cells(time, cells),
+            RecorderWriter::Recording(writer) => writer.write_cells(time, cells),
+        }
+    }
+}
+
+impl RatatuiCameraRecorder {
+    /// Creates a recorder that writes an asciicast v2 recording into `writer`.
+    pub fn new(writer: impl Write + Send + Sync + 'static) -> Self {
+        Self::with_writer(RecorderWriter::Asciicast(AsciicastWriter::new(Box::new(
+            writer,
+        ))))
+    }
+
+    /// Creates a recorder that writes an asciicast v2 recording into a new file at `path`.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+
+    /// Creates a recorder that writes a RatatuiCameraRecording into `writer`.
+    pub fn new_recording(writer: impl Write + Send + Sync + 'static) -> Self {
+        Self::with_writer(RecorderWriter::Recording(
+            RatatuiCameraRecordingWriter::new(Box::new(writer)),
+        ))
+    }
+
+    /// Creates a recorder that writes a RatatuiCameraRecording into a new file at `path`.
+    pub fn create_recording(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::new_recording(BufWriter::new(File::create(path)?)))
+    }
+
+    fn with_writer(writer: RecorderWriter) -> Self {
+        Self {
+            writer: Some(writer),
+            started_at: None,
+        }
+    }
+}
+
+fn record_ratatui_cameras_system(
+    time: Res<Time<Real>>,
+    mut ratatui_cameras: Query<
+        (Ref<RatatuiCameraCells>, &mut RatatuiCameraRecorder),
+        With<RatatuiCamera>,
+    >,
+) {
+    for (cells, mut recorder) in &mut ratatui_cameras {
+        if recorder.started_at.is_some() && !cells.is_changed() {
+            continue;
+        }
+
+        let started_at = *recorder.started_at.get_or_insert(time.elapsed());
+        let Some(writer) = recorder.writer.as_mut() else {
+            continue;
+        };
+
+        if let Err(error) = writer.write_cells(time.elapsed() - started_at, &cells) {
+            log::error!("stopped recording ratatui camera: {error}");
+            recorder.writer = None;
+        }
+    }
+}
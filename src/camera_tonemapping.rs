@@ -0,0 +1,321 @@
+use bevy::core_pipeline::tonemapping::Tonemapping;
+use bevy::prelude::*;
+use image::{DynamicImage, RgbaImage};
+
+use crate::RatatuiCamera;
+
+pub struct RatatuiCameraTonemappingPlugin;
+
+impl Plugin for RatatuiCameraTonemappingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, disable_bevy_tonemapping_system)
+            .add_observer(handle_ratatui_tonemapping_removal_system::<RatatuiCamera>)
+            .add_observer(handle_ratatui_tonemapping_removal_system::<RatatuiCameraTonemapping>);
+    }
+}
+
+/// The largest finite half precision float. HDR values are clamped to this before tonemapping, so
+/// that infinite or NaN values cannot produce NaN colors.
+const MAX_HDR_VALUE: f32 = 65504.0;
+
+/// When spawned with a RatatuiCamera on a camera with `hdr: true`, the camera will render to a
+/// high dynamic range `Rgba16Float` texture, and this component controls how those values are
+/// mapped into a displayable range when the rendered image is unpacked on the CPU, before it is
+/// converted into terminal colors. HDR cameras without this component will simply clamp values.
+///
+/// Bevy's own `Tonemapping` would otherwise be applied to the HDR texture before it is unpacked, so
+/// HDR cameras with this component have their `Tonemapping` set to `Tonemapping::None`, making the
+/// unpacking step the only tonemapping applied. The original `Tonemapping` is restored when this
+/// component is removed or HDR is turned off. Without this component, Bevy's tonemapping is left
+/// as it is.
+///
+#[derive(Component, Clone, Copy)]
+pub struct RatatuiCameraTonemapping {
+    /// Exposure adjustment in stops, applied before tonemapping. Each increase of 1.0 doubles the
+    /// brightness of the rendered image.
+    pub exposure: f32,
+
+    /// The operator used to compress high dynamic range values into the 0.0-1.0 range.
+    pub operator: TonemappingOperator,
+}
+
+impl Default for RatatuiCameraTonemapping {
+    fn default() -> Self {
+        Self {
+            exposure: 0.0,
+            operator: TonemappingOperator::default(),
+        }
+    }
+}
+
+/// Specify the curve used to compress high dynamic range values into the 0.0-1.0 range.
+///
+#[derive(Default, Clone, Copy)]
+pub enum TonemappingOperator {
+    /// Values above 1.0 are clipped.
+    #[default]
+    Clamp,
+
+    /// Each color channel is compressed with `x / (1 + x)`.
+    Reinhard,
+
+    /// Color is compressed based on its luminance, which preserves hue better than `Reinhard`.
+    ReinhardLuminance,
+
+    /// Krzysztof Narkowicz's fitted approximation of the ACES filmic curve.
+    AcesFitted,
+}
+
+impl RatatuiCameraTonemapping {
+    /// Converts an `Rgba16Float` image into an 8-bit sRGB image using this tonemapping.
+    pub(crate) fn tonemap_image(&self, image: &Image) -> DynamicImage {
+        let exposure_scale = 2f32.powf(self.exposure);
+
+        let data = image
+            .data
+            .chunks_exact(8)
+            .flat_map(|pixel| {
+                let [red, green, blue, alpha] = [0, 2, 4, 6].map(|offset| {
+                    f16_to_f32(u16::from_le_bytes([pixel[offset], pixel[offset + 1]]))
+                });
+
+                let [red, green, blue] = [red, green, blue].map(|channel| {
+                    let channel = channel * exposure_scale;
+                    if channel.is_nan() {
+                        0.0
+                    } else {
+                        channel.clamp(0.0, MAX_HDR_VALUE)
+                    }
+                });
+
+                let color = LinearRgba::rgb(red, green, blue);
+                let color = self.operator.apply(color).with_alpha(alpha.clamp(0.0, 1.0));
+
+                Srgba::from(color).to_u8_array()
+            })
+            .collect();
+
+        let image = RgbaImage::from_raw(image.width(), image.height(), data)
+            .expect("failed to create tonemapped image buffer");
+
+        DynamicImage::ImageRgba8(image)
+    }
+}
+
+impl TonemappingOperator {
+    fn apply(&self, color: LinearRgba) -> LinearRgba {
+        let LinearRgba {
+            red, green, blue, ..
+        } = color;
+
+        let [red, green, blue] = match self {
+            TonemappingOperator::Clamp => [red, green, blue],
+            TonemappingOperator::Reinhard => [red, green, blue].map(|x| x / (1.0 + x)),
+            TonemappingOperator::ReinhardLuminance => {
+                let luminance = color.luminance();
+                let scale = 1.0 / (1.0 + luminance);
+                [red * scale, green * scale, blue * scale]
+            }
+            TonemappingOperator::AcesFitted => {
+                [red, green, blue].map(|x| (x * (2.51 * x + 0.03)) / (x * (2.43 * x + 0.59) + 0.14))
+            }
+        };
+
+        LinearRgba::rgb(
+            red.clamp(0.0, 1.0),
+            green.clamp(0.0, 1.0),
+            blue.clamp(0.0, 1.0),
+        )
+    }
+}
+
+/// The `Tonemapping` of a camera before it was set to `Tonemapping::None`, restored once the
+/// readback is no longer tonemapped on the CPU.
+#[derive(Component)]
+struct OriginalTonemapping(Tonemapping);
+
+/// Turns off Bevy's tonemapping on HDR cameras whose readback is tonemapped on the CPU, so that the
+/// rendered image is not tonemapped twice, and restores it on cameras that no longer render HDR.
+fn disable_bevy_tonemapping_system(
+    mut commands: Commands,
+    mut ratatui_cameras: Query<
+        (
+            Entity,
+            &Camera,
+            &mut Tonemapping,
+            Option<&OriginalTonemapping>,
+        ),
+        (With<RatatuiCamera>, With<RatatuiCameraTonemapping>),
+    >,
+) {
+    for (entity_id, camera, mut tonemapping, original_tonemapping) in &mut ratatui_cameras {
+        if camera.hdr && *tonemapping != Tonemapping::None {
+            commands
+                .entity(entity_id)
+                .insert(OriginalTonemapping(*tonemapping));
+            *tonemapping = Tonemapping::None;
+        } else if let (false, Some(original_tonemapping)) = (camera.hdr, original_tonemapping) {
+            *tonemapping = original_tonemapping.0;
+            commands.entity(entity_id).remove::<OriginalTonemapping>();
+        }
+    }
+}
+
+fn handle_ratatui_tonemapping_removal_system<T: Component>(
+    trigger: Trigger<OnRemove, T>,
+    mut commands: Commands,
+    mut ratatui_cameras: Query<(&mut Tonemapping, &OriginalTonemapping)>,
+) {
+    if let Ok((mut tonemapping, original_tonemapping)) = ratatui_cameras.get_mut(trigger.entity()) {
+        *tonemapping = original_tonemapping.0;
+        commands
+            .entity(trigger.entity())
+            .remove::<OriginalTonemapping>();
+    }
+}
+
+/// Decodes an IEEE 754 half precision float.
+fn f16_to_f32(bits: u16) -> f32 {
+    let sign = if bits & 0x8000 == 0 { 1.0 } else { -1.0 };
+    let exponent = ((bits >> 10) & 0x1f) as i32;
+    let mantissa = (bits & 0x03ff) as f32;
+
+    match exponent {
+        0 => sign * mantissa * 2f32.powi(-24),
+        0x1f if mantissa == 0.0 => sign * f32::INFINITY,
+        0x1f => f32::NAN,
+        _ => sign * (1.0 + mantissa / 1024.0) * 2f32.powi(exponent - 15),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::render::render_asset::RenderAssetUsages;
+    use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+    use super::*;
+
+    #[test]
+    fn half_floats() {
+        assert_eq!(f16_to_f32(0x0000), 0.0);
+        assert_eq!(f16_to_f32(0x3c00), 1.0);
+        assert_eq!(f16_to_f32(0xc000), -2.0);
+        assert_eq!(f16_to_f32(0x7bff), MAX_HDR_VALUE);
+        assert_eq!(f16_to_f32(0x0001), 2f32.powi(-24));
+        assert_eq!(f16_to_f32(0x03ff), 1023.0 * 2f32.powi(-24));
+        assert_eq!(f16_to_f32(0x0400), 2f32.powi(-14));
+        assert_eq!(f16_to_f32(0x7c00), f32::INFINITY);
+        assert_eq!(f16_to_f32(0xfc00), f32::NEG_INFINITY);
+        assert!(f16_to_f32(0x7e00).is_nan());
+        assert!(f16_to_f32(0xfe00).is_nan());
+    }
+
+    #[test]
+    fn operators() {
+        let operators = [
+            TonemappingOperator::Clamp,
+            TonemappingOperator::Reinhard,
+            TonemappingOperator::ReinhardLuminance,
+            TonemappingOperator::AcesFitted,
+        ];
+
+        for operator in operators {
+            assert_eq!(operator.apply(LinearRgba::BLACK), LinearRgba::BLACK);
+
+            for value in [0.25, 1.0, 4.0, MAX_HDR_VALUE] {
+                let color = operator.apply(LinearRgba::rgb(value, value, value));
+                for channel in [color.red, color.green, color.blue] {
+                    assert!((0.0..=1.0).contains(&channel));
+                }
+            }
+        }
+
+        let color = TonemappingOperator::Clamp.apply(LinearRgba::rgb(0.5, 2.0, 0.0));
+        assert_eq!(color, LinearRgba::rgb(0.5, 1.0, 0.0));
+
+        let color = TonemappingOperator::Reinhard.apply(LinearRgba::rgb(1.0, 3.0, 0.0));
+        assert_eq!(color, LinearRgba::rgb(0.5, 0.75, 0.0));
+
+        let color = TonemappingOperator::AcesFitted.apply(LinearRgba::rgb(1.0, 1.0, 1.0));
+        assert!((color.red - 0.8038).abs() < 0.001);
+    }
+
+    fn tonemap(half_floats: [u16; 4]) -> [u8; 4] {
+        let data = half_floats
+            .iter()
+            .flat_map(|bits| bits.to_le_bytes())
+            .collect();
+        let image = Image::new(
+            Extent3d::default(),
+            TextureDimension::D2,
+            data,
+            TextureFormat::Rgba16Float,
+            RenderAssetUsages::default(),
+        );
+
+        let tonemapping = RatatuiCameraTonemapping {
+            operator: TonemappingOperator::Reinhard,
+            ..default()
+        };
+
+        tonemapping
+            .tonemap_image(&image)
+            .to_rgba8()
+            .get_pixel(0, 0)
+            .0
+    }
+
+    #[test]
+    fn non_finite_values() {
+        assert_eq!(tonemap([0x7c00, 0xfc00, 0x7e00, 0x3c00]), [255, 0, 0, 255]);
+        assert_eq!(tonemap([0x0000, 0x0000, 0x0000, 0x7c00]), [0, 0, 0, 255]);
+    }
+
+    fn spawn_camera(app: &mut App) -> Entity {
+        app.world_mut()
+            .spawn((
+                Camera {
+                    hdr: true,
+                    ..default()
+                },
+                Tonemapping::AgX,
+                RatatuiCamera::default(),
+                RatatuiCameraTonemapping::default(),
+            ))
+            .id()
+    }
+
+    fn tonemapping(app: &App, entity: Entity) -> Tonemapping {
+        *app.world().get::<Tonemapping>(entity).unwrap()
+    }
+
+    #[test]
+    fn restore_bevy_tonemapping() {
+        let mut app = App::new();
+        app.add_plugins(RatatuiCameraTonemappingPlugin);
+
+        let entity = spawn_camera(&mut app);
+        app.update();
+        assert_eq!(tonemapping(&app, entity), Tonemapping::None);
+
+        app.world_mut()
+            .entity_mut(entity)
+            .remove::<RatatuiCameraTonemapping>();
+        app.update();
+        assert_eq!(tonemapping(&app, entity), Tonemapping::AgX);
+
+        let entity = spawn_camera(&mut app);
+        app.update();
+        app.world_mut().get_mut::<Camera>(entity).unwrap().hdr = false;
+        app.update();
+        assert_eq!(tonemapping(&app, entity), Tonemapping::AgX);
+
+        app.world_mut().get_mut::<Camera>(entity).unwrap().hdr = true;
+        app.update();
+        assert_eq!(tonemapping(&app, entity), Tonemapping::None);
+
+        app.world_mut().entity_mut(entity).remove::<RatatuiCamera>();
+        app.update();
+        assert_eq!(tonemapping(&app, entity), Tonemapping::AgX);
+    }
+}
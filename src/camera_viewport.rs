@@ -0,0 +1,389 @@
+use std::borrow::Cow;
+
+use bevy::{prelude::*, utils::HashMap};
+use bevy_ratatui::event::{InputSet, MouseEvent};
+use crossterm::event::{MouseButton, MouseEventKind};
+use image::{DynamicImage, GenericImageView};
+
+use crate::{camera_cells::RatatuiCameraSet, RatatuiCamera, RatatuiCameraWidget};
+
+pub struct RatatuiCameraViewportPlugin;
+
+impl Plugin for RatatuiCameraViewportPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PreUpdate,
+            ratatui_camera_viewport_controls_system
+                .after(InputSet::EmitCrossterm)
+                .before(RatatuiCameraSet::Convert),
+        );
+    }
+}
+
+/// Insert this component alongside your `RatatuiCamera` to zoom into and pan around the rendered
+/// image when it is drawn, without moving the camera or changing its projection. Only the visible
+/// region of the image is converted, so rendering at a higher resolution than the terminal and
+/// zooming in reveals more detail.
+///
+/// Positions reported by the RatatuiCameraWidget (e.g. `cell_to_normalized` and `cell_to_ray`),
+/// labels, and glyphs all account for the viewport.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiCamera, RatatuiCameraViewport};
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.spawn((
+///     Camera3d::default(),
+///     RatatuiCamera::default(),
+///     RatatuiCameraViewport::default().with_zoom(2.0),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct RatatuiCameraViewport {
+    /// How far the image is magnified. Values below 1.0 are treated as 1.0 (the whole image).
+    pub zoom: f32,
+
+    /// Offset of the center of the visible region from the center of the image, normalized so
+    /// that 1.0 is the full width or height of the image. The visible region is kept inside of
+    /// the image, so the offset is clamped according to the zoom.
+    pub pan: Vec2,
+}
+
+impl Default for RatatuiCameraViewport {
+    fn default() -> Self {
+        Self {
+            zoom: 1.0,
+            pan: Vec2::ZERO,
+        }
+    }
+}
+
+impl RatatuiCameraViewport {
+    /// Sets the zoom factor.
+    pub fn with_zoom(mut self, zoom: f32) -> Self {
+        self.zoom = zoom;
+        self
+    }
+
+    /// Sets the pan offset.
+    pub fn with_pan(mut self, pan: Vec2) -> Self {
+        self.pan = pan;
+        self
+    }
+
+    /// The visible region of the image, normalized so that (0, 0) is the top left corner and
+    /// (1, 1) is the bottom right corner of the whole image.
+    pub fn visible_rect(&self) -> Rect {
+        let half_size = Vec2::splat(0.5 / self.zoom.max(1.0));
+        let center = Vec2::splat(0.5) + self.clamped_pan(self.pan);
+
+        Rect::from_center_half_size(center, half_size)
+    }
+
+    /// Changes the zoom factor while keeping the given normalized image position (for example,
+    /// the one under the mouse) at the same place in the widget.
+    pub fn zoom_at(&mut self, zoom: f32, normalized: Vec2) {
+        let visible_rect = self.visible_rect();
+        let zoom = zoom.max(1.0);
+        let scale = self.zoom.max(1.0) / zoom;
+
+        let min = normalized - (normalized - visible_rect.min) * scale;
+        let center = min + Vec2::splat(0.5 / zoom);
+
+        self.zoom = zoom;
+        self.pan = self.clamped_pan(center - Vec2::splat(0.5));
+    }
+
+    /// Moves the visible region by a normalized offset.
+    pub fn pan_by(&mut self, delta: Vec2) {
+        self.pan = self.clamped_pan(self.pan + delta);
+    }
+
+    /// The region of an image of the given dimensions that is visible, in whole pixels.
+    pub(crate) fn crop_rect(&self, image_size: (u32, u32)) -> URect {
+        let (image_width, image_height) = image_size;
+        let visible_rect = self.visible_rect();
+
+        let width = ((image_width as f32 * visible_rect.width()).round() as u32)
+            .clamp(1, image_width.max(1));
+        let height = ((image_height as f32 * visible_rect.height()).round() as u32)
+            .clamp(1, image_height.max(1));
+
+        let x = ((image_width as f32 * visible_rect.min.x).round().max(0.0) as u32)
+            .min(image_width.saturating_sub(width));
+        let y = ((image_height as f32 * visible_rect.min.y).round().max(0.0) as u32)
+            .min(image_height.saturating_sub(height));
+
+        URect::new(x, y, x + width, y + height)
+    }
+
+    /// Crops an image to the visible region, borrowing it if the whole image is visible.
+    pub(crate) fn crop<'a>(&self, image: &'a DynamicImage) -> Cow<'a, DynamicImage> {
+        let crop_rect = self.crop_rect(image.dimensions());
+
+        if crop_rect.min == UVec2::ZERO && crop_rect.size() == UVec2::from(image.dimensions()) {
+            return Cow::Borrowed(image);
+        }
+
+        Cow::Owned(image.crop_imm(
+            crop_rect.min.x,
+            crop_rect.min.y,
+            crop_rect.width(),
+            crop_rect.height(),
+        ))
+    }
+
+    fn clamped_pan(&self, pan: Vec2) -> Vec2 {
+        let limit = Vec2::splat(0.5 - 0.5 / self.zoom.max(1.0));
+        pan.clamp(-limit, limit)
+    }
+}
+
+/// Insert this component alongside your `RatatuiCamera` to zoom its RatatuiCameraViewport with
+/// the mouse wheel (centered on the cell under the mouse) and pan it by dragging. A
+/// RatatuiCameraViewport is inserted automatically if the camera does not already have one.
+///
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+#[require(RatatuiCameraViewport)]
+pub struct RatatuiCameraViewportControls {
+    /// Factor the zoom is multiplied (scrolling up) or divided (scrolling down) by for each step
+    /// of the mouse wheel.
+    pub zoom_step: f32,
+
+    /// The maximum zoom factor reachable with the mouse wheel.
+    pub max_zoom: f32,
+
+    /// The mouse button used for dragging the viewport, or `None` to disable dragging.
+    pub drag_button: Option<MouseButton>,
+}
+
+impl Default for RatatuiCameraViewportControls {
+    fn default() -> Self {
+        Self {
+            zoom_step: 1.25,
+            max_zoom: 32.0,
+            drag_button: Some(MouseButton::Left),
+        }
+    }
+}
+
+impl RatatuiCameraViewportControls {
+    /// Sets the zoom step.
+    pub fn with_zoom_step(mut self, zoom_step: f32) -> Self {
+        self.zoom_step = zoom_step;
+        self
+    }
+
+    /// Sets the maximum zoom factor.
+    pub fn with_max_zoom(mut self, max_zoom: f32) -> Self {
+        self.max_zoom = max_zoom;
+        self
+    }
+
+    /// Sets the mouse button used for dragging.
+    pub fn with_drag_button(mut self, drag_button: Option<MouseButton>) -> Self {
+        self.drag_button = drag_button;
+        self
+    }
+}
+
+/// Applies mouse wheel and drag events to the viewports of cameras with viewport controls. The
+/// widget's copy of the viewport is updated immediately, so that the cells converted this frame
+/// already reflect the change.
+fn ratatui_camera_viewport_controls_system(
+    mut mouse_events: EventReader<MouseEvent>,
+    mut ratatui_cameras: Query<
+        (
+            Entity,
+            &RatatuiCameraViewportControls,
+            &mut RatatuiCameraViewport,
+            &mut RatatuiCameraWidget,
+        ),
+        With<RatatuiCamera>,
+    >,
+    mut drag_positions: Local<HashMap<Entity, (u16, u16)>>,
+) {
+    for MouseEvent(mouse_event) in mouse_events.read() {
+        let (column, row) = (mouse_event.column, mouse_event.row);
+
+        for (entity_id, controls, mut viewport, mut widget) in &mut ratatui_cameras {
+            match mouse_event.kind {
+                MouseEventKind::ScrollUp | MouseEventKind::ScrollDown => {
+                    let Some(normalized) = widget.cell_to_normalized(column, row) else {
+                        continue;
+                    };
+
+                    let zoom = match mouse_event.kind {
+                        MouseEventKind::ScrollUp => viewport.zoom * controls.zoom_step,
+                        _ => viewport.zoom / controls.zoom_step,
+                    };
+
+                    viewport.zoom_at(zoom.min(controls.max_zoom), normalized);
+                }
+                MouseEventKind::Down(button)
+                    if controls.drag_button == Some(button)
+                        && widget.cell_to_normalized(column, row).is_some() =>
+                {
+                    drag_positions.insert(entity_id, (column, row));
+                }
+                MouseEventKind::Drag(button) if controls.drag_button == Some(button) => {
+                    let Some(last_position) = drag_positions.get_mut(&entity_id) else {
+                        continue;
+                    };
+
+                    let Some(delta) = widget.cell_delta_to_normalized(
+                        column as i32 - last_position.0 as i32,
+                        row as i32 - last_position.1 as i32,
+                    ) else {
+                        continue;
+                    };
+
+                    *last_position = (column, row);
+                    viewport.pan_by(-delta);
+                }
+                MouseEventKind::Up(_) => {
+                    drag_positions.remove(&entity_id);
+                }
+                _ => {}
+            }
+
+            if widget.viewport != *viewport {
+                widget.viewport = *viewport;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_rect_eq(rect: Rect, min: Vec2, max: Vec2) {
+        assert!(
+            rect.min.abs_diff_eq(min, 1e-6) && rect.max.abs_diff_eq(max, 1e-6),
+            "{rect:?} != {min:?}..{max:?}"
+        );
+    }
+
+    #[test]
+    fn visible_rect() {
+        let viewport = RatatuiCameraViewport::default();
+        assert_rect_eq(viewport.visible_rect(), Vec2::ZERO, Vec2::ONE);
+
+        let viewport = RatatuiCameraViewport::default().with_zoom(2.0);
+        assert_rect_eq(
+            viewport.visible_rect(),
+            Vec2::splat(0.25),
+            Vec2::splat(0.75),
+        );
+
+        let viewport = viewport.with_pan(Vec2::new(0.1, -0.1));
+        assert_rect_eq(
+            viewport.visible_rect(),
+            Vec2::new(0.35, 0.15),
+            Vec2::new(0.85, 0.65),
+        );
+    }
+
+    #[test]
+    fn zoom_below_one() {
+        let viewport = RatatuiCameraViewport::default()
+            .with_zoom(0.5)
+            .with_pan(Vec2::new(0.3, 0.3));
+
+        assert_rect_eq(viewport.visible_rect(), Vec2::ZERO, Vec2::ONE);
+    }
+
+    #[test]
+    fn pan_is_clamped() {
+        let viewport = RatatuiCameraViewport::default()
+            .with_zoom(4.0)
+            .with_pan(Vec2::new(1.0, -1.0));
+        assert_rect_eq(
+            viewport.visible_rect(),
+            Vec2::new(0.75, 0.0),
+            Vec2::new(1.0, 0.25),
+        );
+
+        let mut viewport = RatatuiCameraViewport::default().with_zoom(2.0);
+        viewport.pan_by(Vec2::new(0.2, 0.0));
+        viewport.pan_by(Vec2::new(0.2, 0.0));
+        assert!(viewport.pan.abs_diff_eq(Vec2::new(0.25, 0.0), 1e-6));
+
+        let mut viewport = RatatuiCameraViewport::default();
+        viewport.pan_by(Vec2::new(0.2, -0.3));
+        assert_eq!(viewport.pan, Vec2::ZERO);
+    }
+
+    #[test]
+    fn zoom_at() {
+        let mut viewport = RatatuiCameraViewport::default();
+        viewport.zoom_at(2.0, Vec2::splat(0.5));
+        assert_eq!(viewport.zoom, 2.0);
+        assert_rect_eq(
+            viewport.visible_rect(),
+            Vec2::splat(0.25),
+            Vec2::splat(0.75),
+        );
+
+        // The position under the mouse stays at the same place in the widget.
+        let mut viewport = RatatuiCameraViewport::default();
+        viewport.zoom_at(2.0, Vec2::new(0.4, 0.6));
+        assert_rect_eq(
+            viewport.visible_rect(),
+            Vec2::new(0.2, 0.3),
+            Vec2::new(0.7, 0.8),
+        );
+
+        // Zooming in at a corner keeps the visible region inside of the image.
+        let mut viewport = RatatuiCameraViewport::default();
+        viewport.zoom_at(4.0, Vec2::ZERO);
+        assert_rect_eq(viewport.visible_rect(), Vec2::ZERO, Vec2::splat(0.25));
+
+        let mut viewport = RatatuiCameraViewport::default();
+        viewport.zoom_at(4.0, Vec2::ONE);
+        assert_rect_eq(viewport.visible_rect(), Vec2::splat(0.75), Vec2::ONE);
+
+        // Zooming out past 1.0 shows the whole image again.
+        viewport.zoom_at(0.25, Vec2::ONE);
+        assert_eq!(viewport.zoom, 1.0);
+        assert_eq!(viewport.pan, Vec2::ZERO);
+        assert_rect_eq(viewport.visible_rect(), Vec2::ZERO, Vec2::ONE);
+    }
+
+    #[test]
+    fn crop_rect() {
+        let viewport = RatatuiCameraViewport::default();
+        assert_eq!(viewport.crop_rect((64, 32)), URect::new(0, 0, 64, 32));
+
+        let viewport = RatatuiCameraViewport::default().with_zoom(2.0);
+        assert_eq!(viewport.crop_rect((64, 32)), URect::new(16, 8, 48, 24));
+
+        let viewport = viewport.with_pan(Vec2::new(0.5, 0.5));
+        assert_eq!(viewport.crop_rect((64, 32)), URect::new(32, 16, 64, 32));
+
+        // The visible region is always at least one pixel, and inside of the image.
+        let viewport = RatatuiCameraViewport::default()
+            .with_zoom(1000.0)
+            .with_pan(Vec2::splat(0.5));
+        assert_eq!(viewport.crop_rect((10, 10)), URect::new(9, 9, 10, 10));
+    }
+
+    #[test]
+    fn crop() {
+        let image = DynamicImage::new_rgba8(8, 4);
+
+        let viewport = RatatuiCameraViewport::default();
+        assert!(matches!(viewport.crop(&image), Cow::Borrowed(_)));
+
+        let viewport = RatatuiCameraViewport::default().with_zoom(2.0);
+        let cropped = viewport.crop(&image);
+        assert!(matches!(cropped, Cow::Owned(_)));
+        assert_eq!(cropped.dimensions(), (4, 2));
+    }
+}
@@ -1,16 +1,53 @@
 mod camera;
+mod camera_cells;
 mod camera_edge_detection;
 mod camera_image_pipe;
 mod camera_node;
 mod camera_node_sobel;
+mod camera_overlay;
+mod camera_picking;
 mod camera_readback;
+mod camera_recorder;
+mod camera_tonemapping;
+mod camera_viewport;
 mod plugin;
 mod widget;
+mod widget_ansi;
+mod widget_asciicast;
+mod widget_cells;
 mod widget_halfblocks;
+mod widget_html;
+mod widget_layout;
 mod widget_luminance;
 mod widget_none;
+mod widget_overlay;
+mod widget_player;
+mod widget_raster;
+mod widget_raster_font;
+mod widget_recording;
+mod widget_sobel;
+mod widget_stack;
 
-pub use camera::{LuminanceConfig, RatatuiCamera, RatatuiCameraStrategy};
+pub use camera::{
+    AutoresizePolicy, LuminanceConfig, RatatuiCamera, RatatuiCameraRedraw,
+    RatatuiCameraRenderPolicy, RatatuiCameraSource, RatatuiCameraStrategy,
+    RatatuiCameraTransparency,
+};
+pub use camera_cells::RatatuiCameraSet;
 pub use camera_edge_detection::{EdgeCharacters, RatatuiCameraEdgeDetection};
-pub use plugin::RatatuiCameraPlugin;
+pub use camera_overlay::{RatatuiGlyph, RatatuiLabel};
+pub use camera_picking::{RatatuiCameraMouseEvent, RatatuiCameraPointerPlugin, RATATUI_POINTER_ID};
+pub use camera_recorder::RatatuiCameraRecorder;
+pub use camera_tonemapping::{RatatuiCameraTonemapping, TonemappingOperator};
+pub use camera_viewport::{RatatuiCameraViewport, RatatuiCameraViewportControls};
+pub use plugin::{RatatuiCameraHeadlessPlugin, RatatuiCameraPlugin};
 pub use widget::RatatuiCameraWidget;
+pub use widget_asciicast::AsciicastWriter;
+pub use widget_cells::{RatatuiCameraCell, RatatuiCameraCells};
+pub use widget_layout::{FitMode, LayoutAlignment, RatatuiCameraLayout, ResizeFilter};
+pub use widget_player::{RatatuiCameraPlayer, RatatuiCameraPlayerPlugin};
+pub use widget_raster::RatatuiCameraRasterizer;
+pub use widget_recording::{
+    RatatuiCameraRecordedFrame, RatatuiCameraRecording, RatatuiCameraRecordingWriter,
+};
+pub use widget_stack::RatatuiCameraStack;
@@ -1,8 +1,12 @@
 use bevy::prelude::*;
 
 use crate::{
-    camera_node::RatatuiCameraNodePlugin, camera_node_sobel::RatatuiCameraNodeSobelPlugin,
-    camera_readback::RatatuiCameraReadbackPlugin,
+    camera_cells::RatatuiCameraCellsPlugin, camera_node::RatatuiCameraNodePlugin,
+    camera_node_sobel::RatatuiCameraNodeSobelPlugin, camera_overlay::RatatuiCameraOverlayPlugin,
+    camera_picking::RatatuiCameraPickingPlugin, camera_readback::RatatuiCameraReadbackPlugin,
+    camera_recorder::RatatuiCameraRecorderPlugin,
+    camera_tonemapping::RatatuiCameraTonemappingPlugin,
+    camera_viewport::RatatuiCameraViewportPlugin,
 };
 
 /// Add this plugin, add a RatatuiCamera component to your camera, and then a RatatuiCameraWidget
@@ -65,14 +69,39 @@ use crate::{
 /// }
 /// ```
 ///
+/// In an app without a terminal, add the RatatuiCameraHeadlessPlugin instead.
+///
 pub struct RatatuiCameraPlugin;
 
 impl Plugin for RatatuiCameraPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((
-            RatatuiCameraNodePlugin,
-            RatatuiCameraNodeSobelPlugin,
-            RatatuiCameraReadbackPlugin,
-        ));
+        add_ratatui_camera_plugins(app, false);
+        app.add_plugins((RatatuiCameraPickingPlugin, RatatuiCameraViewportPlugin));
     }
 }
+
+/// Add this plugin instead of the RatatuiCameraPlugin in an app without a terminal (for example a
+/// headless server or a test harness). Systems that depend on a terminal (the `RatatuiContext` and
+/// terminal resize and mouse events provided by bevy_ratatui) are not added, so `RatatuiPlugins`
+/// are not required, and the dimensions of each RatatuiCamera must be set explicitly rather than
+/// coming from terminal resize events.
+///
+pub struct RatatuiCameraHeadlessPlugin;
+
+impl Plugin for RatatuiCameraHeadlessPlugin {
+    fn build(&self, app: &mut App) {
+        add_ratatui_camera_plugins(app, true);
+    }
+}
+
+fn add_ratatui_camera_plugins(app: &mut App, headless: bool) {
+    app.add_plugins((
+        RatatuiCameraNodePlugin,
+        RatatuiCameraNodeSobelPlugin,
+        RatatuiCameraReadbackPlugin { headless },
+        RatatuiCameraOverlayPlugin,
+        RatatuiCameraCellsPlugin,
+        RatatuiCameraRecorderPlugin,
+        RatatuiCameraTonemappingPlugin,
+    ));
+}
@@ -1,49 +1,322 @@
-use bevy::prelude::Component;
-use image::DynamicImage;
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::{Camera, Component, GlobalTransform, Ray3d, UVec2, Vec2};
+use bevy::utils::Instant;
+use image::{DynamicImage, GenericImageView};
+use ratatui::prelude::*;
 use ratatui::widgets::Widget;
-use ratatui::{prelude::*, widgets::WidgetRef};
 
+use crate::camera_overlay::{ProjectedGlyph, ProjectedLabel};
+use crate::widget_cells::RatatuiCameraCells;
 use crate::widget_halfblocks::RatatuiCameraWidgetHalfblocks;
+use crate::widget_layout::{layout_images, ImageLayout};
 use crate::widget_luminance::RatatuiCameraWidgetLuminance;
 use crate::widget_none::RatatuiCameraWidgetNone;
-use crate::{RatatuiCameraEdgeDetection, RatatuiCameraStrategy};
+use crate::widget_overlay::{render_glyphs, render_labels};
+use crate::{
+    RatatuiCameraEdgeDetection, RatatuiCameraLayout, RatatuiCameraStrategy,
+    RatatuiCameraTransparency, RatatuiCameraViewport,
+};
 
 /// Ratatui widget that will be inserted into each RatatuiCamera containing entity and updated each
 /// frame with the last image rendered by the camera. When drawn in a ratatui buffer, it will use
 /// the RatatuiCamera's specified RatatuiCameraStrategy to convert the rendered image to unicode
 /// characters, and will draw them in the buffer.
 ///
+/// The widget is only changed when a new frame is received (or when the labels or glyphs projected
+/// into it move), so `Changed<RatatuiCameraWidget>` can be used to skip redundant work in draw
+/// systems.
+///
+/// A widget can also be created from any image with `RatatuiCameraWidget::from_image`, to convert
+/// loaded or generated images with the same strategies, without a camera or a GPU.
+///
 #[derive(Component)]
 pub struct RatatuiCameraWidget {
-    pub camera_image: DynamicImage,
-    pub sobel_image: Option<DynamicImage>,
+    /// The last image rendered by the camera.
+    pub camera_image: Arc<DynamicImage>,
+
+    /// The last image produced by the edge detection step, if edge detection is enabled.
+    pub sobel_image: Option<Arc<DynamicImage>>,
+
+    /// The strategy used to convert the image into unicode characters.
     pub strategy: RatatuiCameraStrategy,
+
+    /// The edge detection settings used when converting the sobel image.
     pub edge_detection: Option<RatatuiCameraEdgeDetection>,
+
+    /// How the image is resampled and positioned in the area it is drawn in.
+    pub layout: RatatuiCameraLayout,
+
+    /// How transparent pixels in the image are drawn.
+    pub transparency: RatatuiCameraTransparency,
+
+    /// The region of the image that is drawn, for zooming and panning.
+    pub viewport: RatatuiCameraViewport,
+
+    /// Monotonically increasing number of the current frame, incremented for each new frame.
+    pub frame: u64,
+
+    /// When the current frame was read back from the GPU, for measuring end-to-end latency.
+    pub captured_at: Instant,
+
+    /// The area the widget was last drawn in, used when autoresizing the camera.
+    pub(crate) last_area: Mutex<Option<Rect>>,
+
+    /// RatatuiLabels visible to the camera, projected into the rendered image.
+    pub(crate) labels: Vec<ProjectedLabel>,
+
+    /// RatatuiGlyphs visible to the camera, projected into the rendered image.
+    pub(crate) glyphs: Vec<ProjectedGlyph>,
+
+    /// Converted (and post-processed) cells, along with what they were converted from.
+    pub(crate) cells: Option<(CellsSource, Arc<RatatuiCameraCells>)>,
 }
 
-impl Widget for &RatatuiCameraWidget {
-    fn render(self, area: Rect, buf: &mut Buffer) {
+/// Everything that a widget's cells are converted from, so that converted cells can be reused
+/// until the frame, the area, or any of the conversion settings change. Images are compared by
+/// pointer, so nothing as large as an image or a grid of cells is ever compared.
+#[derive(Clone)]
+pub(crate) struct CellsSource {
+    frame: u64,
+    camera_image: Arc<DynamicImage>,
+    sobel_image: Option<Arc<DynamicImage>>,
+    area: Rect,
+    strategy: RatatuiCameraStrategy,
+    edge_detection: Option<RatatuiCameraEdgeDetection>,
+    layout: RatatuiCameraLayout,
+    transparency: RatatuiCameraTransparency,
+    viewport: RatatuiCameraViewport,
+}
+
+impl CellsSource {
+    pub(crate) fn new(widget: &RatatuiCameraWidget, area: Rect) -> Self {
+        Self {
+            frame: widget.frame,
+            camera_image: widget.camera_image.clone(),
+            sobel_image: widget.sobel_image.clone(),
+            area,
+            strategy: widget.strategy.clone(),
+            edge_detection: widget.edge_detection,
+            layout: widget.layout,
+            transparency: widget.transparency,
+            viewport: widget.viewport,
+        }
+    }
+
+    /// Whether cells converted from this source are what the widget would convert for the area.
+    pub(crate) fn matches(&self, widget: &RatatuiCameraWidget, area: Rect) -> bool {
+        let is_same_sobel_image = match (&self.sobel_image, &widget.sobel_image) {
+            (Some(source), Some(current)) => Arc::ptr_eq(source, current),
+            (None, None) => true,
+            _ => false,
+        };
+
+        self.frame == widget.frame
+            && Arc::ptr_eq(&self.camera_image, &widget.camera_image)
+            && is_same_sobel_image
+            && self.area == area
+            && self.strategy == widget.strategy
+            && self.edge_detection == widget.edge_detection
+            && self.layout == widget.layout
+            && self.transparency == widget.transparency
+            && self.viewport == widget.viewport
+    }
+}
+
+impl RatatuiCameraWidget {
+    /// Creates a widget that converts an image (instead of a camera's render) using a strategy,
+    /// with the default layout, transparency, and viewport. The widget's public fields can be
+    /// changed afterwards.
+    ///
+    /// # Example:
+    ///
+    /// ```no_run
+    /// # use bevy_ratatui_camera::{LuminanceConfig, RatatuiCameraEdgeDetection};
+    /// # use bevy_ratatui_camera::{RatatuiCameraStrategy, RatatuiCameraWidget};
+    /// # fn convert() -> image::ImageResult<()> {
+    /// let image = image::open("scene.png")?;
+    /// let widget = RatatuiCameraWidget::from_image(
+    ///     image,
+    ///     RatatuiCameraStrategy::Luminance(LuminanceConfig::default()),
+    /// )
+    /// .with_edge_detection(RatatuiCameraEdgeDetection::default());
+    ///
+    /// print!("{}", widget.to_ansi(80, 24));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_image(image: DynamicImage, strategy: RatatuiCameraStrategy) -> Self {
+        Self {
+            camera_image: Arc::new(image),
+            sobel_image: None,
+            strategy,
+            edge_detection: None,
+            layout: RatatuiCameraLayout::default(),
+            transparency: RatatuiCameraTransparency::default(),
+            viewport: RatatuiCameraViewport::default(),
+            frame: 0,
+            captured_at: Instant::now(),
+            last_area: Mutex::default(),
+            labels: Vec::new(),
+            glyphs: Vec::new(),
+            cells: None,
+        }
+    }
+
+    /// Sets the sobel image used for drawing edges, whose channels hold the strength of vertical,
+    /// horizontal, forward diagonal and backward diagonal edges (as produced by
+    /// `RatatuiCameraEdgeDetection::detect_edges`). Only used if edge detection is also set.
+    pub fn with_sobel_image(mut self, sobel_image: DynamicImage) -> Self {
+        self.sobel_image = Some(Arc::new(sobel_image));
+        self
+    }
+
+    /// Sets the edge detection settings. If no sobel image has been set, edges are detected in the
+    /// image on the CPU (refer to `RatatuiCameraEdgeDetection::detect_edges`).
+    pub fn with_edge_detection(mut self, edge_detection: RatatuiCameraEdgeDetection) -> Self {
+        if self.sobel_image.is_none() {
+            self.sobel_image = Some(Arc::new(edge_detection.detect_edges(&self.camera_image)));
+        }
+
+        self.edge_detection = Some(edge_detection);
+        self
+    }
+
+    /// The area of the buffer that this widget was last drawn in, if it has been drawn.
+    pub fn last_area(&self) -> Option<Rect> {
+        self.last_area.lock().ok().and_then(|last_area| *last_area)
+    }
+
+    /// Maps the center of a terminal cell to a position in the rendered image, normalized so that
+    /// (0, 0) is the top left corner and (1, 1) is the bottom right corner. Returns `None` if the
+    /// widget has not been drawn yet, or if the cell is outside of the drawn image (for example,
+    /// in the empty bars left when the aspect ratios of the image and the area do not match).
+    pub fn cell_to_normalized(&self, column: u16, row: u16) -> Option<Vec2> {
+        self.image_layout(self.last_area()?)
+            .cell_to_normalized(column, row)
+    }
+
+    /// Maps a distance in terminal cells (for example, between two mouse positions while dragging)
+    /// to a normalized distance in the rendered image. Returns `None` if the widget has not been
+    /// drawn yet.
+    pub fn cell_delta_to_normalized(&self, columns: i32, rows: i32) -> Option<Vec2> {
+        Some(
+            self.image_layout(self.last_area()?)
+                .cell_delta_to_normalized(columns, rows),
+        )
+    }
+
+    /// Maps the center of a terminal cell to a position in the viewport of the camera, in logical
+    /// pixels, as expected by `Camera::viewport_to_world` and similar methods.
+    pub fn cell_to_viewport(&self, column: u16, row: u16, camera: &Camera) -> Option<Vec2> {
+        let normalized = self.cell_to_normalized(column, row)?;
+        let viewport_size = camera.logical_viewport_size()?;
+
+        Some(normalized * viewport_size)
+    }
+
+    /// Maps the center of a terminal cell to a ray in world space, passing from the camera through
+    /// the point in the scene drawn in that cell. Pass the camera and global transform from the
+    /// entity this widget belongs to.
+    pub fn cell_to_ray(
+        &self,
+        column: u16,
+        row: u16,
+        camera: &Camera,
+        camera_transform: &GlobalTransform,
+    ) -> Option<Ray3d> {
+        let viewport_position = self.cell_to_viewport(column, row, camera)?;
+
+        camera
+            .viewport_to_world(camera_transform, viewport_position)
+            .ok()
+    }
+
+    /// Converts the last rendered image into cells using the widget's strategy, as they would be
+    /// drawn if the widget were drawn in the given area.
+    pub fn cells(&self, area: Rect) -> RatatuiCameraCells {
+        let camera_image = self.viewport.crop(&self.camera_image);
+        let sobel_image = self
+            .sobel_image
+            .as_deref()
+            .map(|sobel_image| self.viewport.crop(sobel_image));
+
+        let (camera_image, sobel_image, render_area) =
+            layout_images(&camera_image, sobel_image.as_deref(), area, &self.layout);
+
         match self.strategy {
             RatatuiCameraStrategy::HalfBlocks => {
-                RatatuiCameraWidgetHalfblocks::new(&self.camera_image).render_ref(area, buf)
+                RatatuiCameraWidgetHalfblocks::new(&camera_image, &self.transparency)
+                    .convert(render_area)
             }
             RatatuiCameraStrategy::Luminance(ref strategy_config) => {
                 RatatuiCameraWidgetLuminance::new(
-                    &self.camera_image,
-                    &self.sobel_image,
+                    &camera_image,
+                    sobel_image.as_ref(),
                     strategy_config,
                     &self.edge_detection,
+                    &self.transparency,
                 )
-                .render_ref(area, buf);
-            }
-            RatatuiCameraStrategy::None => {
-                RatatuiCameraWidgetNone::new(
-                    &self.camera_image,
-                    &self.sobel_image,
-                    &self.edge_detection,
-                )
-                .render_ref(area, buf);
+                .convert(render_area)
             }
+            RatatuiCameraStrategy::None => RatatuiCameraWidgetNone::new(
+                &camera_image,
+                sobel_image.as_ref(),
+                &self.edge_detection,
+                &self.transparency,
+            )
+            .convert(render_area),
         }
     }
+
+    /// Converts the last rendered image for an area of the given size (in cells) and serializes it
+    /// as ANSI-escaped text, as in a `.ans` file. Refer to `RatatuiCameraCells::to_ansi`. Only the
+    /// output of the strategy is included, without post-processing or overlays; serialize a
+    /// `RatatuiCameraCells` captured from a drawn `Buffer` to include those.
+    pub fn to_ansi(&self, width: u16, height: u16) -> String {
+        self.cells(Rect::new(0, 0, width, height)).to_ansi()
+    }
+
+    /// Converts the last rendered image for an area of the given size (in cells) and serializes it
+    /// as a standalone HTML document. Refer to `RatatuiCameraCells::to_html`. As with `to_ansi`,
+    /// only the output of the strategy is included.
+    pub fn to_html(&self, width: u16, height: u16) -> String {
+        self.cells(Rect::new(0, 0, width, height)).to_html()
+    }
+
+    /// Draws the converted camera image, recording the area it was drawn in.
+    pub(crate) fn render_image(&self, area: Rect, buf: &mut Buffer) {
+        if let Ok(mut last_area) = self.last_area.lock() {
+            *last_area = Some(area);
+        }
+
+        match &self.cells {
+            Some((source, cells)) if source.matches(self, area) => cells.draw(buf),
+            _ => self.cells(area).draw(buf),
+        }
+    }
+
+    /// Draws the projected glyphs and labels over the converted camera image.
+    pub(crate) fn render_overlays(&self, area: Rect, buf: &mut Buffer) {
+        let image_layout = self.image_layout(area);
+        render_glyphs(&self.glyphs, &image_layout, buf);
+        render_labels(&self.labels, &image_layout, buf);
+    }
+
+    fn image_layout(&self, area: Rect) -> ImageLayout {
+        let image_size = UVec2::from(self.camera_image.dimensions()).max(UVec2::ONE);
+        let crop_rect = self.viewport.crop_rect(self.camera_image.dimensions());
+
+        ImageLayout::new(crop_rect.size().into(), area, &self.layout).with_source(
+            crop_rect.min.as_vec2() / image_size.as_vec2(),
+            crop_rect.size().as_vec2() / image_size.as_vec2(),
+        )
+    }
+}
+
+impl Widget for &RatatuiCameraWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_image(area, buf);
+        self.render_overlays(area, buf);
+    }
 }
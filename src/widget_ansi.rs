@@ -0,0 +1,271 @@
+use std::fmt::Write;
+
+use ratatui::prelude::*;
+
+use crate::{RatatuiCameraCell, RatatuiCameraCells};
+
+/// Colors used for `Color::Reset` when exporting to formats without a terminal, standing in for
+/// the terminal's default colors.
+pub(crate) const DEFAULT_FG: [u8; 3] = [229, 229, 229];
+pub(crate) const DEFAULT_BG: [u8; 3] = [0, 0, 0];
+
+/// A cell with every property resolved, as it appears when printed to an empty terminal (empty
+/// properties fall back to a blank space and the terminal's default colors).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct ResolvedCell {
+    pub symbol: char,
+    pub style: ResolvedStyle,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct ResolvedStyle {
+    pub fg: Color,
+    pub bg: Color,
+    pub modifier: Modifier,
+}
+
+impl From<&RatatuiCameraCell> for ResolvedCell {
+    fn from(cell: &RatatuiCameraCell) -> Self {
+        Self {
+            symbol: cell.symbol.unwrap_or(' '),
+            style: ResolvedStyle {
+                fg: cell.fg.unwrap_or(Color::Reset),
+                bg: cell.bg.unwrap_or(Color::Reset),
+                modifier: cell.modifier,
+            },
+        }
+    }
+}
+
+/// Appends an SGR escape sequence that resets all attributes and then applies the given style.
+pub(crate) fn write_sgr(out: &mut String, style: &ResolvedStyle) {
+    out.push_str("\x1b[0");
+
+    for (modifier, code) in [
+        (Modifier::BOLD, 1),
+        (Modifier::DIM, 2),
+        (Modifier::ITALIC, 3),
+        (Modifier::UNDERLINED, 4),
+        (Modifier::SLOW_BLINK, 5),
+        (Modifier::RAPID_BLINK, 6),
+        (Modifier::REVERSED, 7),
+        (Modifier::HIDDEN, 8),
+        (Modifier::CROSSED_OUT, 9),
+    ] {
+        if style.modifier.contains(modifier) {
+            let _ = write!(out, ";{code}");
+        }
+    }
+
+    write_sgr_color(out, style.fg, 30);
+    write_sgr_color(out, style.bg, 40);
+
+    out.push('m');
+}
+
+/// Appends the SGR parameters for a color, where `base` is 30 for foreground colors and 40 for
+/// background colors.
+fn write_sgr_color(out: &mut String, color: Color, base: u8) {
+    let _ = match color {
+        Color::Reset => return,
+        Color::Black => write!(out, ";{}", base),
+        Color::Red => write!(out, ";{}", base + 1),
+        Color::Green => write!(out, ";{}", base + 2),
+        Color::Yellow => write!(out, ";{}", base + 3),
+        Color::Blue => write!(out, ";{}", base + 4),
+        Color::Magenta => write!(out, ";{}", base + 5),
+        Color::Cyan => write!(out, ";{}", base + 6),
+        Color::Gray => write!(out, ";{}", base + 7),
+        Color::DarkGray => write!(out, ";{}", base + 60),
+        Color::LightRed => write!(out, ";{}", base + 61),
+        Color::LightGreen => write!(out, ";{}", base + 62),
+        Color::LightYellow => write!(out, ";{}", base + 63),
+        Color::LightBlue => write!(out, ";{}", base + 64),
+        Color::LightMagenta => write!(out, ";{}", base + 65),
+        Color::LightCyan => write!(out, ";{}", base + 66),
+        Color::White => write!(out, ";{}", base + 67),
+        Color::Indexed(index) => write!(out, ";{};5;{index}", base + 8),
+        Color::Rgb(r, g, b) => write!(out, ";{};2;{r};{g};{b}", base + 8),
+    };
+}
+
+impl RatatuiCameraCells {
+    /// Serializes the cells as ANSI-escaped text (as in a `.ans` file), one line per row of the
+    /// grid, with SGR escape sequences wherever the style changes. Empty cell properties are
+    /// printed as a blank space in the terminal's default colors.
+    pub fn to_ansi(&self) -> String {
+        let mut ansi = String::new();
+
+        for row in self.rows() {
+            let mut style: Option<ResolvedStyle> = None;
+
+            for cell in row {
+                let cell = ResolvedCell::from(cell);
+
+                if style != Some(cell.style) {
+                    write_sgr(&mut ansi, &cell.style);
+                    style = Some(cell.style);
+                }
+
+                ansi.push(cell.symbol);
+            }
+
+            ansi.push_str("\x1b[0m\n");
+        }
+
+        ansi
+    }
+}
+
+/// The RGB value of a color as displayed by a typical terminal (using the xterm palette), with
+/// `Color::Reset` resolving to the given default.
+pub(crate) fn color_to_rgb(color: Color, default: [u8; 3]) -> [u8; 3] {
+    match color {
+        Color::Reset => default,
+        Color::Black => indexed_to_rgb(0),
+        Color::Red => indexed_to_rgb(1),
+        Color::Green => indexed_to_rgb(2),
+        Color::Yellow => indexed_to_rgb(3),
+        Color::Blue => indexed_to_rgb(4),
+        Color::Magenta => indexed_to_rgb(5),
+        Color::Cyan => indexed_to_rgb(6),
+        Color::Gray => indexed_to_rgb(7),
+        Color::DarkGray => indexed_to_rgb(8),
+        Color::LightRed => indexed_to_rgb(9),
+        Color::LightGreen => indexed_to_rgb(10),
+        Color::LightYellow => indexed_to_rgb(11),
+        Color::LightBlue => indexed_to_rgb(12),
+        Color::LightMagenta => indexed_to_rgb(13),
+        Color::LightCyan => indexed_to_rgb(14),
+        Color::White => indexed_to_rgb(15),
+        Color::Indexed(index) => indexed_to_rgb(index),
+        Color::Rgb(r, g, b) => [r, g, b],
+    }
+}
+
+/// The RGB value of a color in the xterm 256 color palette.
+fn indexed_to_rgb(index: u8) -> [u8; 3] {
+    const ANSI_COLORS: [[u8; 3]; 16] = [
+        [0, 0, 0],
+        [205, 0, 0],
+        [0, 205, 0],
+        [205, 205, 0],
+        [0, 0, 238],
+        [205, 0, 205],
+        [0, 205, 205],
+        [229, 229, 229],
+        [127, 127, 127],
+        [255, 0, 0],
+        [0, 255, 0],
+        [255, 255, 0],
+        [92, 92, 255],
+        [255, 0, 255],
+        [0, 255, 255],
+        [255, 255, 255],
+    ];
+
+    match index {
+        0..=15 => ANSI_COLORS[index as usize],
+        16..=231 => {
+            let cube_level = |level: u8| if level == 0 { 0 } else { 55 + level * 40 };
+            let index = index - 16;
+            [
+                cube_level(index / 36),
+                cube_level((index / 6) % 6),
+                cube_level(index % 6),
+            ]
+        }
+        232..=255 => {
+            let gray = 8 + (index - 232) * 10;
+            [gray, gray, gray]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cell(symbol: char, fg: Option<Color>, bg: Option<Color>) -> RatatuiCameraCell {
+        RatatuiCameraCell {
+            symbol: Some(symbol),
+            fg,
+            bg,
+            modifier: Modifier::empty(),
+        }
+    }
+
+    #[test]
+    fn sgr() {
+        let sgr = |fg, bg, modifier| {
+            let mut out = String::new();
+            write_sgr(&mut out, &ResolvedStyle { fg, bg, modifier });
+            out
+        };
+
+        assert_eq!(
+            sgr(Color::Reset, Color::Reset, Modifier::empty()),
+            "\x1b[0m"
+        );
+        assert_eq!(
+            sgr(Color::Red, Color::DarkGray, Modifier::empty()),
+            "\x1b[0;31;100m"
+        );
+        assert_eq!(
+            sgr(Color::White, Color::Black, Modifier::empty()),
+            "\x1b[0;97;40m"
+        );
+        assert_eq!(
+            sgr(Color::Indexed(42), Color::Rgb(1, 2, 3), Modifier::empty()),
+            "\x1b[0;38;5;42;48;2;1;2;3m"
+        );
+        assert_eq!(
+            sgr(
+                Color::Reset,
+                Color::Reset,
+                Modifier::BOLD | Modifier::UNDERLINED | Modifier::CROSSED_OUT
+            ),
+            "\x1b[0;1;4;9m"
+        );
+    }
+
+    #[test]
+    fn to_ansi() {
+        let mut cells = RatatuiCameraCells::new(Rect::new(3, 4, 3, 2));
+        cells.cells = vec![
+            cell('a', Some(Color::Red), None),
+            cell('b', Some(Color::Red), None),
+            cell('c', Some(Color::Blue), None),
+            RatatuiCameraCell::default(),
+            cell('e', None, Some(Color::Green)),
+            RatatuiCameraCell {
+                modifier: Modifier::ITALIC,
+                ..cell('f', None, Some(Color::Green))
+            },
+        ];
+
+        assert_eq!(
+            cells.to_ansi(),
+            "\x1b[0;31mab\x1b[0;34mc\x1b[0m\n\
+             \x1b[0m \x1b[0;42me\x1b[0;3;42mf\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn xterm_palette() {
+        let rgb = |color| color_to_rgb(color, [1, 2, 3]);
+
+        assert_eq!(rgb(Color::Reset), [1, 2, 3]);
+        assert_eq!(rgb(Color::Red), [205, 0, 0]);
+        assert_eq!(rgb(Color::White), [255, 255, 255]);
+        assert_eq!(rgb(Color::Indexed(8)), [127, 127, 127]);
+        assert_eq!(rgb(Color::Indexed(16)), [0, 0, 0]);
+        assert_eq!(rgb(Color::Indexed(21)), [0, 0, 255]);
+        assert_eq!(rgb(Color::Indexed(67)), [95, 135, 175]);
+        assert_eq!(rgb(Color::Indexed(196)), [255, 0, 0]);
+        assert_eq!(rgb(Color::Indexed(231)), [255, 255, 255]);
+        assert_eq!(rgb(Color::Indexed(232)), [8, 8, 8]);
+        assert_eq!(rgb(Color::Indexed(255)), [238, 238, 238]);
+        assert_eq!(rgb(Color::Rgb(4, 5, 6)), [4, 5, 6]);
+    }
+}
@@ -0,0 +1,274 @@
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::time::Duration;
+
+use ratatui::prelude::*;
+use unicode_width::UnicodeWidthChar;
+
+use crate::widget_ansi::{write_sgr, ResolvedCell, ResolvedStyle};
+use crate::RatatuiCameraCells;
+
+/// Writes frames of cells as an [asciicast v2](https://docs.asciinema.org/manual/asciicast/v2/)
+/// recording, playable in any asciinema player. Each frame is emitted as an output event
+/// containing only the cells that changed since the previous frame, as cursor movements and SGR
+/// escape sequences.
+///
+/// The header is written with the first frame, sized to fit the frame's area. If a later frame's
+/// area has a different size, a resize event is emitted and the whole frame is redrawn.
+///
+/// Frames can be the cells of a RatatuiCamera (see `RatatuiCameraRecorder`), or a whole ratatui
+/// frame by converting the `Buffer` returned from drawing the terminal:
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use bevy_ratatui_camera::{AsciicastWriter, RatatuiCameraCells};
+/// # use ratatui::buffer::Buffer;
+/// # fn record(writer: &mut AsciicastWriter<std::fs::File>, buffer: &Buffer, time: Duration) {
+/// writer.write_cells(time, &RatatuiCameraCells::from(buffer)).unwrap();
+/// # }
+/// ```
+///
+pub struct AsciicastWriter<W: Write> {
+    writer: W,
+    size: Option<(u16, u16)>,
+    previous_cells: Option<RatatuiCameraCells>,
+}
+
+impl<W: Write> AsciicastWriter<W> {
+    /// Creates a writer that writes the recording into `writer`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            size: None,
+            previous_cells: None,
+        }
+    }
+
+    /// Writes a frame of cells, `time` after the start of the recording.
+    pub fn write_cells(&mut self, time: Duration, cells: &RatatuiCameraCells) -> io::Result<()> {
+        let size = (cells.area.right(), cells.area.bottom());
+        let mut output = String::new();
+
+        match self.size {
+            None => {
+                writeln!(
+                    self.writer,
+                    r#"{{"version": 2, "width": {}, "height": {}}}"#,
+                    size.0, size.1
+                )?;
+                self.size = Some(size);
+                self.previous_cells = None;
+            }
+            Some(previous_size) if previous_size != size => {
+                self.write_event(time, "r", &format!("{}x{}", size.0, size.1))?;
+                self.size = Some(size);
+                self.previous_cells = None;
+                output.push_str("\x1b[0m\x1b[2J");
+            }
+            _ => {}
+        }
+
+        let previous_cells = self
+            .previous_cells
+            .as_ref()
+            .filter(|previous_cells| previous_cells.area == cells.area);
+
+        let mut cursor: Option<Position> = None;
+        let mut style: Option<ResolvedStyle> = None;
+
+        // Like ratatui's buffer diff, the cells covered by the trailing columns of a wide symbol
+        // are skipped, and cells that were covered by a wide symbol in the previous frame are
+        // redrawn even if they are unchanged.
+        let mut to_skip = 0;
+        let mut invalidated = 0;
+
+        for (index, (position, cell)) in cells.iter().enumerate() {
+            let cell = ResolvedCell::from(cell);
+            let previous_cell = previous_cells
+                .and_then(|previous_cells| previous_cells.cells.get(index))
+                .map(ResolvedCell::from);
+
+            let width = symbol_width(cell.symbol);
+            let previous_width =
+                previous_cell.map_or(1, |previous_cell| symbol_width(previous_cell.symbol));
+
+            let is_unchanged = previous_cell == Some(cell) && invalidated == 0;
+            let is_covered = to_skip > 0;
+
+            to_skip = width.saturating_sub(1);
+            invalidated = width.max(previous_width).max(invalidated).saturating_sub(1);
+
+            if is_unchanged || is_covered {
+                continue;
+            }
+
+            if cursor != Some(position) {
+                let _ = write!(output, "\x1b[{};{}H", position.y + 1, position.x + 1);
+            }
+
+            if style != Some(cell.style) {
+                write_sgr(&mut output, &cell.style);
+                style = Some(cell.style);
+            }
+
+            output.push(cell.symbol);
+            cursor = Some(Position::new(position.x + width as u16, position.y));
+        }
+
+        match self.previous_cells.as_mut() {
+            Some(previous_cells) => previous_cells.clone_from(cells),
+            None => self.previous_cells = Some(cells.clone()),
+        }
+
+        if output.is_empty() {
+            return Ok(());
+        }
+
+        output.push_str("\x1b[0m");
+        self.write_event(time, "o", &output)
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn write_event(&mut self, time: Duration, code: &str, data: &str) -> io::Result<()> {
+        writeln!(
+            self.writer,
+            "[{:.6}, \"{code}\", {}]",
+            time.as_secs_f64(),
+            json_string(data)
+        )?;
+
+        self.writer.flush()
+    }
+}
+
+/// The number of columns a symbol covers when printed.
+fn symbol_width(symbol: char) -> usize {
+    symbol.width().unwrap_or(0)
+}
+
+/// Quotes and escapes a string as a JSON string literal.
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+
+    for character in value.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            character if character.is_control() => {
+                let _ = write!(json, "\\u{:04x}", character as u32);
+            }
+            character => json.push(character),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RatatuiCameraCell;
+
+    /// A row of cells printing `symbols`, where `None` is the blank cell following a wide symbol.
+    fn cells(area: Rect, symbols: &[Option<char>]) -> RatatuiCameraCells {
+        let mut cells = RatatuiCameraCells::new(area);
+        for (cell, symbol) in cells.cells.iter_mut().zip(symbols) {
+            *cell = RatatuiCameraCell {
+                symbol: Some(symbol.unwrap_or(' ')),
+                ..Default::default()
+            };
+        }
+        cells
+    }
+
+    /// The lines written for each frame, with the output events' data unescaped.
+    fn write_frames(frames: &[RatatuiCameraCells]) -> Vec<String> {
+        let mut writer = AsciicastWriter::new(Vec::new());
+        for (index, cells) in frames.iter().enumerate() {
+            writer
+                .write_cells(Duration::from_millis(index as u64 * 500), cells)
+                .unwrap();
+        }
+
+        String::from_utf8(writer.into_inner())
+            .unwrap()
+            .lines()
+            .map(|line| line.replace("\\u001b", "\x1b"))
+            .collect()
+    }
+
+    /// An output event line.
+    fn output(time: &str, data: &str) -> String {
+        format!(r#"[{time}, "o", "{data}"]"#)
+    }
+
+    #[test]
+    fn header_and_resize() {
+        let lines = write_frames(&[
+            cells(Rect::new(0, 0, 2, 1), &[Some('a'), Some('b')]),
+            cells(Rect::new(1, 0, 2, 2), &[Some('c')]),
+        ]);
+
+        assert_eq!(lines[0], r#"{"version": 2, "width": 2, "height": 1}"#);
+        assert_eq!(lines[1], output("0.000000", "\x1b[1;1H\x1b[0mab\x1b[0m"));
+        assert_eq!(lines[2], r#"[0.500000, "r", "3x2"]"#);
+        assert_eq!(
+            lines[3],
+            output(
+                "0.500000",
+                "\x1b[0m\x1b[2J\x1b[1;2H\x1b[0mc \x1b[2;2H  \x1b[0m"
+            )
+        );
+        assert_eq!(lines.len(), 4);
+    }
+
+    #[test]
+    fn changed_cells() {
+        let area = Rect::new(0, 0, 3, 2);
+        let lines = write_frames(&[
+            cells(area, &[Some('a'), Some('b'), Some('c'), Some('d')]),
+            cells(area, &[Some('a'), Some('x'), Some('c'), Some('y')]),
+            cells(area, &[Some('a'), Some('x'), Some('c'), Some('y')]),
+        ]);
+
+        assert_eq!(
+            lines[2],
+            output("0.500000", "\x1b[1;2H\x1b[0mx\x1b[2;1Hy\x1b[0m")
+        );
+        assert_eq!(lines.len(), 3);
+    }
+
+    #[test]
+    fn wide_symbols() {
+        let area = Rect::new(0, 0, 4, 1);
+        let lines = write_frames(&[
+            cells(area, &[Some('a'), Some('b'), Some('c'), Some('d')]),
+            cells(area, &[Some('界'), None, Some('c'), Some('e')]),
+            cells(area, &[Some('a'), Some('b'), Some('c'), Some('e')]),
+        ]);
+
+        // The blank cell covered by the wide symbol is skipped, and the cursor has moved past it.
+        assert_eq!(
+            lines[2],
+            output("0.500000", "\x1b[1;1H\x1b[0m界\x1b[1;4He\x1b[0m")
+        );
+
+        // The cell covered by the wide symbol is redrawn when it is replaced, though it is
+        // unchanged.
+        assert_eq!(lines[3], output("1.000000", "\x1b[1;1H\x1b[0mab\x1b[0m"));
+    }
+
+    #[test]
+    fn json_escaping() {
+        assert_eq!(json_string("a\"b\\c\nd\u{1}"), r#""a\"b\\c\nd\u0001""#);
+    }
+}
@@ -0,0 +1,311 @@
+use std::borrow::Cow;
+
+use bevy::prelude::Component;
+use bevy::tasks::{ComputeTaskPool, ParallelSliceMut, TaskPool};
+use image::{DynamicImage, RgbaImage};
+use ratatui::buffer::Cell;
+use ratatui::prelude::*;
+
+/// The number of rows in each band of cells converted by a single task.
+const ROWS_PER_BAND: usize = 8;
+
+/// Grids with fewer cells than this are converted on the calling thread.
+const MIN_PARALLEL_CELLS: usize = 4096;
+
+/// A single terminal cell converted from a RatatuiCamera's rendered image. Properties that are
+/// `None` leave the corresponding property of the buffer cell untouched when the cell is drawn,
+/// which is how transparent areas of the image are represented.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RatatuiCameraCell {
+    /// The character printed in the cell.
+    pub symbol: Option<char>,
+
+    /// The foreground (text) color of the cell.
+    pub fg: Option<Color>,
+
+    /// The background color of the cell.
+    pub bg: Option<Color>,
+
+    /// Modifiers (bold, italic, etc.) added to the cell.
+    pub modifier: Modifier,
+}
+
+impl RatatuiCameraCell {
+    /// A cell printing `symbol` in the `fg` color, leaving the background untouched.
+    pub fn new(symbol: char, fg: Color) -> Self {
+        Self {
+            symbol: Some(symbol),
+            fg: Some(fg),
+            ..Default::default()
+        }
+    }
+
+    /// A blank cell using the terminal's default colors.
+    pub fn reset() -> Self {
+        Self {
+            symbol: Some(' '),
+            fg: Some(Color::Reset),
+            bg: Some(Color::Reset),
+            ..Default::default()
+        }
+    }
+
+    /// Whether drawing this cell leaves the buffer cell untouched.
+    pub fn is_empty(&self) -> bool {
+        self.symbol.is_none() && self.fg.is_none() && self.bg.is_none() && self.modifier.is_empty()
+    }
+
+    /// Applies the properties of this cell to a ratatui buffer cell.
+    pub fn apply(&self, cell: &mut Cell) {
+        if let Some(symbol) = self.symbol {
+            cell.set_char(symbol);
+        }
+
+        if let Some(fg) = self.fg {
+            cell.set_fg(fg);
+        }
+
+        if let Some(bg) = self.bg {
+            cell.set_bg(bg);
+        }
+
+        if !self.modifier.is_empty() {
+            cell.modifier.insert(self.modifier);
+        }
+    }
+}
+
+/// A grid of cells converted from a RatatuiCamera's rendered image by its RatatuiCameraStrategy,
+/// along with the area of the buffer it is drawn in. Every strategy produces one of these, and
+/// drawing a RatatuiCameraWidget draws its cells into the buffer, so the grid can be used to
+/// inspect, post-process, serialize, or diff converted frames.
+///
+/// Each RatatuiCamera entity whose widget has been drawn also has this as a component, holding the
+/// cells for the area the widget was last drawn in. Refer to `RatatuiCameraSet` for modifying them.
+///
+#[derive(Component, Debug, Default, PartialEq)]
+pub struct RatatuiCameraCells {
+    /// The area of the buffer that the cells are drawn in.
+    pub area: Rect,
+
+    /// The cells, in row-major order.
+    pub cells: Vec<RatatuiCameraCell>,
+}
+
+impl Clone for RatatuiCameraCells {
+    fn clone(&self) -> Self {
+        Self {
+            area: self.area,
+            cells: self.cells.clone(),
+        }
+    }
+
+    /// Reuses the existing allocation, as cells are copied every frame.
+    fn clone_from(&mut self, source: &Self) {
+        self.area = source.area;
+        self.cells.clone_from(&source.cells);
+    }
+}
+
+/// Captures the contents of a ratatui buffer (for example, the whole terminal frame returned from
+/// drawing), keeping the first character of each cell's symbol.
+impl From<&Buffer> for RatatuiCameraCells {
+    fn from(buffer: &Buffer) -> Self {
+        Self {
+            area: buffer.area,
+            cells: buffer
+                .content
+                .iter()
+                .map(|cell| RatatuiCameraCell {
+                    symbol: cell.symbol().chars().next(),
+                    fg: Some(cell.fg),
+                    bg: Some(cell.bg),
+                    modifier: cell.modifier,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl RatatuiCameraCells {
+    /// Creates a grid of empty cells covering the given area.
+    pub fn new(area: Rect) -> Self {
+        Self {
+            area,
+            cells: vec![RatatuiCameraCell::default(); area.area() as usize],
+        }
+    }
+
+    /// The cell at a position in the buffer, if it is inside of the grid's area.
+    pub fn get(&self, position: impl Into<Position>) -> Option<&RatatuiCameraCell> {
+        self.index_of(position.into())
+            .map(|index| &self.cells[index])
+    }
+
+    /// The cell at a position in the buffer, if it is inside of the grid's area.
+    pub fn get_mut(&mut self, position: impl Into<Position>) -> Option<&mut RatatuiCameraCell> {
+        self.index_of(position.into())
+            .map(|index| &mut self.cells[index])
+    }
+
+    /// Iterates over the cells along with their positions in the buffer.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &RatatuiCameraCell)> {
+        self.area.positions().zip(self.cells.iter())
+    }
+
+    /// Iterates mutably over the cells along with their positions in the buffer.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Position, &mut RatatuiCameraCell)> {
+        self.area.positions().zip(self.cells.iter_mut())
+    }
+
+    /// Iterates over the rows of cells, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[RatatuiCameraCell]> {
+        self.cells.chunks(self.area.width.max(1) as usize)
+    }
+
+    /// Draws the cells into a buffer, skipping any that fall outside of it.
+    pub fn draw(&self, buf: &mut Buffer) {
+        for (position, cell) in self.iter() {
+            if let Some(buffer_cell) = buf.cell_mut(position) {
+                cell.apply(buffer_cell);
+            }
+        }
+    }
+
+    /// Fills in the cells one row at a time, calling `convert_row` with each row's offset from the
+    /// top of the area. Large grids are split into bands of rows that are converted in parallel on
+    /// Bevy's compute task pool.
+    pub(crate) fn convert_rows<F>(&mut self, convert_row: F)
+    where
+        F: Fn(u16, &mut [RatatuiCameraCell]) + Send + Sync,
+    {
+        let width = self.area.width as usize;
+        if width == 0 {
+            return;
+        }
+
+        let convert_band = |first_row: usize, band: &mut [RatatuiCameraCell]| {
+            for (row_offset, row) in band.chunks_mut(width).enumerate() {
+                convert_row((first_row + row_offset) as u16, row);
+            }
+        };
+
+        // Spawning tasks costs more than it saves for small grids.
+        if self.cells.len() < MIN_PARALLEL_CELLS {
+            convert_band(0, &mut self.cells);
+            return;
+        }
+
+        let task_pool = ComputeTaskPool::get_or_init(TaskPool::default);
+        self.cells
+            .par_chunk_map_mut(task_pool, width * ROWS_PER_BAND, |band_index, band| {
+                convert_band(band_index * ROWS_PER_BAND, band);
+            });
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        if !self.area.contains(position) {
+            return None;
+        }
+
+        let x = (position.x - self.area.x) as usize;
+        let y = (position.y - self.area.y) as usize;
+
+        Some(x + y * self.area.width as usize)
+    }
+}
+
+/// Returns the image as 8-bit RGBA, only copying it if it is in a different format.
+pub(crate) fn as_rgba8(image: &DynamicImage) -> Cow<'_, RgbaImage> {
+    match image.as_rgba8() {
+        Some(rgba_image) => Cow::Borrowed(rgba_image),
+        None => Cow::Owned(image.to_rgba8()),
+    }
+}
+
+/// Averages the vertically adjacent pair of pixels covered by the cell at `(x, y)` into a single
+/// RGBA value (cells are half as tall as they are wide, so each covers two pixels). If the image
+/// has an odd number of rows, cells in the last row use only the upper pixel.
+pub(crate) fn rgba_quad(image: &RgbaImage, x: u32, y: u32) -> Option<[u8; 4]> {
+    let upper = image.get_pixel_checked(x, y * 2)?.0;
+    let Some(lower) = image.get_pixel_checked(x, y * 2 + 1) else {
+        return Some(upper);
+    };
+
+    Some(std::array::from_fn(|channel| {
+        ((upper[channel] as u16 + lower[channel] as u16) / 2) as u8
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_cell(row: u16, x: usize) -> RatatuiCameraCell {
+        RatatuiCameraCell::new(
+            char::from(b'a' + (x % 26) as u8),
+            Color::Indexed((row as usize * 7 + x) as u8),
+        )
+    }
+
+    fn convert(area: Rect) -> RatatuiCameraCells {
+        let mut cells = RatatuiCameraCells::new(area);
+        cells.convert_rows(|row, cells| {
+            for (x, cell) in cells.iter_mut().enumerate() {
+                *cell = test_cell(row, x);
+            }
+        });
+
+        cells
+    }
+
+    fn serial(area: Rect) -> Vec<RatatuiCameraCell> {
+        (0..area.height)
+            .flat_map(|row| (0..area.width as usize).map(move |x| test_cell(row, x)))
+            .collect()
+    }
+
+    #[test]
+    fn convert_rows_serially() {
+        let area = Rect::new(2, 3, 10, 5);
+        assert!((area.area() as usize) < MIN_PARALLEL_CELLS);
+        assert_eq!(convert(area).cells, serial(area));
+    }
+
+    #[test]
+    fn convert_rows_in_parallel() {
+        // The last band is shorter than the rest.
+        let area = Rect::new(0, 0, 100, 45);
+        assert!(area.area() as usize >= MIN_PARALLEL_CELLS);
+        assert_ne!(area.height as usize % ROWS_PER_BAND, 0);
+        assert_eq!(convert(area).cells, serial(area));
+    }
+
+    #[test]
+    fn convert_rows_without_width() {
+        let cells = convert(Rect::new(0, 0, 0, 10));
+        assert!(cells.cells.is_empty());
+    }
+
+    #[test]
+    fn positions() {
+        let mut cells = RatatuiCameraCells::new(Rect::new(2, 3, 4, 2));
+        *cells.get_mut((5, 4)).unwrap() = RatatuiCameraCell::reset();
+
+        assert_eq!(cells.index_of(Position::new(5, 4)), Some(7));
+        assert_eq!(cells.get((5, 4)), Some(&RatatuiCameraCell::reset()));
+        assert_eq!(cells.get((6, 4)), None);
+        assert_eq!(cells.get((1, 3)), None);
+        assert_eq!(cells.rows().count(), 2);
+    }
+
+    #[test]
+    fn odd_image_height() {
+        let image = RgbaImage::from_fn(1, 3, |_, y| image::Rgba([y as u8 * 100, 0, 0, 255]));
+
+        assert_eq!(rgba_quad(&image, 0, 0), Some([50, 0, 0, 255]));
+        assert_eq!(rgba_quad(&image, 0, 1), Some([200, 0, 0, 255]));
+        assert_eq!(rgba_quad(&image, 0, 2), None);
+    }
+}
@@ -1,42 +1,75 @@
 use image::DynamicImage;
-use ratatui::widgets::Widget;
-use ratatui::{prelude::*, widgets::WidgetRef};
-use ratatui_image::{
-    picker::{Picker, ProtocolType},
-    FilterType, Resize,
-};
+use ratatui::prelude::*;
+
+use crate::widget_cells::{as_rgba8, RatatuiCameraCell, RatatuiCameraCells};
+use crate::RatatuiCameraTransparency;
 
 pub struct RatatuiCameraWidgetHalfblocks<'a> {
     camera_image: &'a DynamicImage,
+    transparency: &'a RatatuiCameraTransparency,
 }
 
 impl<'a> RatatuiCameraWidgetHalfblocks<'a> {
-    pub fn new(camera_image: &'a DynamicImage) -> Self {
-        Self { camera_image }
+    pub fn new(
+        camera_image: &'a DynamicImage,
+        transparency: &'a RatatuiCameraTransparency,
+    ) -> Self {
+        Self {
+            camera_image,
+            transparency,
+        }
     }
-}
 
-impl WidgetRef for RatatuiCameraWidgetHalfblocks<'_> {
-    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
-        let mut picker = Picker::from_fontsize((1, 2));
-        picker.set_protocol_type(ProtocolType::Halfblocks);
+    /// Converts the (already resized) camera image into cells covering the render area.
+    pub fn convert(&self, render_area: Rect) -> RatatuiCameraCells {
+        let camera_image = as_rgba8(self.camera_image);
+        let transparency = self.transparency;
+        let mut cells = RatatuiCameraCells::new(render_area);
+
+        cells.convert_rows(|y, row| {
+            for (x, target) in row.iter_mut().enumerate() {
+                let (x, y) = (x as u32, y as u32);
+                let upper = camera_image.get_pixel_checked(x, y * 2);
+                let lower = camera_image.get_pixel_checked(x, y * 2 + 1);
 
-        let camera_image = self.camera_image.resize(
-            area.width as u32,
-            area.height as u32 * 2,
-            FilterType::Nearest,
-        );
+                // Pixels are `None` when transparent, or when past the bottom of an image with an
+                // odd number of rows, in which case the lower half is drawn black.
+                let upper = upper
+                    .filter(|pixel| !transparency.is_transparent(pixel[3]))
+                    .map(|pixel| Color::Rgb(pixel[0], pixel[1], pixel[2]));
+                let lower = match lower {
+                    Some(pixel) if transparency.is_transparent(pixel[3]) => None,
+                    Some(pixel) => Some(Color::Rgb(pixel[0], pixel[1], pixel[2])),
+                    None if *transparency == RatatuiCameraTransparency::Opaque => {
+                        Some(Color::Rgb(0, 0, 0))
+                    }
+                    None => None,
+                };
 
-        let render_area = Rect {
-            x: area.x + area.width.saturating_sub(camera_image.width() as u16) / 2,
-            y: area.y + (area.height * 2).saturating_sub(camera_image.height() as u16) / 4,
-            ..area
-        };
+                let reset_background =
+                    (*transparency == RatatuiCameraTransparency::Reset).then_some(Color::Reset);
 
-        let image_as_halfblocks = picker
-            .new_protocol(camera_image, render_area, Resize::Fit(None))
-            .unwrap();
+                *target = match (upper, lower) {
+                    (Some(upper), Some(lower)) => RatatuiCameraCell {
+                        bg: Some(lower),
+                        ..RatatuiCameraCell::new('▀', upper)
+                    },
+                    (Some(upper), None) => RatatuiCameraCell {
+                        bg: reset_background,
+                        ..RatatuiCameraCell::new('▀', upper)
+                    },
+                    (None, Some(lower)) => RatatuiCameraCell {
+                        bg: reset_background,
+                        ..RatatuiCameraCell::new('▄', lower)
+                    },
+                    (None, None) => match transparency {
+                        RatatuiCameraTransparency::Reset => RatatuiCameraCell::reset(),
+                        _ => RatatuiCameraCell::default(),
+                    },
+                };
+            }
+        });
 
-        ratatui_image::Image::new(&image_as_halfblocks).render(render_area, buf);
+        cells
     }
 }
@@ -0,0 +1,172 @@
+use std::fmt::Write;
+
+use ratatui::prelude::*;
+
+use crate::widget_ansi::{color_to_rgb, ResolvedCell, ResolvedStyle, DEFAULT_BG, DEFAULT_FG};
+use crate::RatatuiCameraCells;
+
+impl RatatuiCameraCells {
+    /// Serializes the cells as a standalone HTML document containing a `<pre>` element, with
+    /// consecutive cells of the same style grouped into `<span>` elements with inline colors.
+    /// Empty cell properties are printed as a blank space in the default colors (light gray on
+    /// black).
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+
+        let _ = write!(
+            html,
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n</head>\n\
+             <body style=\"margin: 0; background-color: {bg};\">\n\
+             <pre style=\"margin: 0; font-family: monospace; line-height: 1; color: {fg}; \
+             background-color: {bg};\">",
+            fg = hex(DEFAULT_FG),
+            bg = hex(DEFAULT_BG),
+        );
+
+        for row in self.rows() {
+            let mut span: Option<(ResolvedStyle, String)> = None;
+
+            for cell in row {
+                let cell = ResolvedCell::from(cell);
+
+                match span.as_mut() {
+                    Some((style, text)) if *style == cell.style => text.push(cell.symbol),
+                    _ => {
+                        if let Some((style, text)) = span.take() {
+                            write_span(&mut html, &style, &text);
+                        }
+                        span = Some((cell.style, cell.symbol.to_string()));
+                    }
+                }
+            }
+
+            if let Some((style, text)) = span {
+                write_span(&mut html, &style, &text);
+            }
+
+            html.push('\n');
+        }
+
+        html.push_str("</pre>\n</body>\n</html>\n");
+        html
+    }
+}
+
+fn write_span(html: &mut String, style: &ResolvedStyle, text: &str) {
+    let (mut fg, mut bg) = (
+        color_to_rgb(style.fg, DEFAULT_FG),
+        color_to_rgb(style.bg, DEFAULT_BG),
+    );
+
+    if style.modifier.contains(Modifier::REVERSED) {
+        (fg, bg) = (bg, fg);
+    }
+
+    let _ = write!(
+        html,
+        "<span style=\"color: {}; background-color: {};",
+        hex(fg),
+        hex(bg)
+    );
+
+    for (modifier, css) in [
+        (Modifier::BOLD, " font-weight: bold;"),
+        (Modifier::DIM, " opacity: 0.5;"),
+        (Modifier::ITALIC, " font-style: italic;"),
+        (Modifier::HIDDEN, " visibility: hidden;"),
+    ] {
+        if style.modifier.contains(modifier) {
+            html.push_str(css);
+        }
+    }
+
+    match (
+        style.modifier.contains(Modifier::UNDERLINED),
+        style.modifier.contains(Modifier::CROSSED_OUT),
+    ) {
+        (true, true) => html.push_str(" text-decoration: underline line-through;"),
+        (true, false) => html.push_str(" text-decoration: underline;"),
+        (false, true) => html.push_str(" text-decoration: line-through;"),
+        (false, false) => {}
+    }
+
+    html.push_str("\">");
+
+    for character in text.chars() {
+        match character {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            character => html.push(character),
+        }
+    }
+
+    html.push_str("</span>");
+}
+
+fn hex([r, g, b]: [u8; 3]) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RatatuiCameraCell;
+
+    use super::*;
+
+    /// The contents of the `<pre>` element.
+    fn pre_contents(html: &str) -> &str {
+        let pre = html.find("<pre").unwrap();
+        let start = pre + html[pre..].find('>').unwrap() + 1;
+        let end = html.find("</pre>").unwrap();
+        &html[start..end]
+    }
+
+    #[test]
+    fn escaping() {
+        let mut cells = RatatuiCameraCells::new(Rect::new(0, 0, 5, 1));
+        for (cell, symbol) in cells.cells.iter_mut().zip(['<', '&', '>', '"', 'a']) {
+            cell.symbol = Some(symbol);
+        }
+
+        assert_eq!(
+            pre_contents(&cells.to_html()),
+            "<span style=\"color: #e5e5e5; background-color: #000000;\">\
+             &lt;&amp;&gt;&quot;a</span>\n"
+        );
+    }
+
+    #[test]
+    fn spans() {
+        let mut cells = RatatuiCameraCells::new(Rect::new(0, 0, 2, 2));
+        cells.cells = vec![
+            RatatuiCameraCell::new('a', Color::Red),
+            RatatuiCameraCell::new('b', Color::Red),
+            RatatuiCameraCell {
+                modifier: Modifier::BOLD | Modifier::REVERSED | Modifier::UNDERLINED,
+                ..RatatuiCameraCell::new('c', Color::Indexed(196))
+            },
+            RatatuiCameraCell {
+                bg: Some(Color::Rgb(1, 2, 3)),
+                ..RatatuiCameraCell::new('d', Color::Reset)
+            },
+        ];
+
+        assert_eq!(
+            pre_contents(&cells.to_html()),
+            "<span style=\"color: #cd0000; background-color: #000000;\">ab</span>\n\
+             <span style=\"color: #000000; background-color: #ff0000; font-weight: bold; \
+             text-decoration: underline;\">c</span>\
+             <span style=\"color: #e5e5e5; background-color: #010203;\">d</span>\n"
+        );
+    }
+
+    #[test]
+    fn document() {
+        let html = RatatuiCameraCells::new(Rect::new(0, 0, 1, 1)).to_html();
+
+        assert!(html.starts_with("<!DOCTYPE html>\n<html>"));
+        assert!(html.ends_with("</pre>\n</body>\n</html>\n"));
+    }
+}
@@ -0,0 +1,277 @@
+use bevy::prelude::*;
+use image::{imageops::FilterType, DynamicImage, GenericImageView};
+use ratatui::layout::Rect;
+
+/// Insert this component alongside your `RatatuiCamera` to control how the rendered image is
+/// resampled and positioned when the RatatuiCameraWidget is drawn into an area whose size does
+/// not match the rendered image.
+///
+#[derive(Component, Default, Clone, Copy, PartialEq)]
+pub struct RatatuiCameraLayout {
+    /// The filter used when resampling the rendered image to the size of the area.
+    pub filter: ResizeFilter,
+
+    /// How the rendered image is scaled to fit the area.
+    pub fit: FitMode,
+
+    /// Where the rendered image is positioned in the area when it does not fill it.
+    pub alignment: LayoutAlignment,
+}
+
+/// Specify the filter used for resampling the rendered image. Filters other than `Nearest` are
+/// slower, but shimmer less when the rendered image size does not match the terminal cells.
+///
+#[derive(Default, Clone, Copy, PartialEq)]
+pub enum ResizeFilter {
+    /// Nearest neighbor sampling.
+    #[default]
+    Nearest,
+
+    /// Linear filtering.
+    Triangle,
+
+    /// Cubic filtering.
+    CatmullRom,
+
+    /// Lanczos filtering with a window of 3.
+    Lanczos3,
+}
+
+impl From<ResizeFilter> for FilterType {
+    fn from(value: ResizeFilter) -> Self {
+        match value {
+            ResizeFilter::Nearest => FilterType::Nearest,
+            ResizeFilter::Triangle => FilterType::Triangle,
+            ResizeFilter::CatmullRom => FilterType::CatmullRom,
+            ResizeFilter::Lanczos3 => FilterType::Lanczos3,
+        }
+    }
+}
+
+/// Specify how the rendered image is scaled to fit the area it is drawn in.
+///
+#[derive(Default, Clone, Copy, PartialEq)]
+pub enum FitMode {
+    /// Scale the image to fit inside the area while maintaining its aspect ratio, leaving empty
+    /// bars along two sides if the aspect ratios do not match.
+    #[default]
+    Fit,
+
+    /// Scale the image to cover the whole area while maintaining its aspect ratio, cropping off
+    /// whatever does not fit.
+    Fill,
+
+    /// Scale the image to exactly the size of the area, ignoring its aspect ratio.
+    Stretch,
+
+    /// Scale the image up by the largest whole number factor that fits inside the area, which
+    /// keeps pixel art crisp. Images larger than the area are scaled down as with `Fit`.
+    Integer,
+}
+
+/// Specify where the rendered image is positioned within the area it is drawn in.
+///
+#[derive(Default, Clone, Copy, PartialEq)]
+pub enum LayoutAlignment {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    #[default]
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl LayoutAlignment {
+    /// Fractions of the free space to put before the image, horizontally and vertically.
+    fn factors(&self) -> (f32, f32) {
+        match self {
+            LayoutAlignment::TopLeft => (0.0, 0.0),
+            LayoutAlignment::Top => (0.5, 0.0),
+            LayoutAlignment::TopRight => (1.0, 0.0),
+            LayoutAlignment::Left => (0.0, 0.5),
+            LayoutAlignment::Center => (0.5, 0.5),
+            LayoutAlignment::Right => (1.0, 0.5),
+            LayoutAlignment::BottomLeft => (0.0, 1.0),
+            LayoutAlignment::Bottom => (0.5, 1.0),
+            LayoutAlignment::BottomRight => (1.0, 1.0),
+        }
+    }
+}
+
+/// Where and how large an image of a given size is drawn in a buffer area. Image dimensions are
+/// in pixels, where each cell of the buffer is one pixel wide and two pixels tall.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImageLayout {
+    /// Dimensions the source image is scaled to.
+    pub scaled_size: (u32, u32),
+
+    /// Offset of the region of the scaled image that is drawn (non-zero when cropping).
+    pub crop_offset: (u32, u32),
+
+    /// Dimensions of the region of the scaled image that is drawn.
+    pub crop_size: (u32, u32),
+
+    /// Area of the buffer that the drawn region occupies.
+    pub render_area: Rect,
+
+    /// Offset of the source image within the whole rendered image, normalized (non-zero when
+    /// zoomed in with a RatatuiCameraViewport).
+    pub source_offset: Vec2,
+
+    /// Size of the source image within the whole rendered image, normalized.
+    pub source_size: Vec2,
+}
+
+impl ImageLayout {
+    /// Calculates the layout of an image with the given dimensions drawn in the given area.
+    pub fn new(image_size: (u32, u32), area: Rect, layout: &RatatuiCameraLayout) -> Self {
+        let (image_width, image_height) = (image_size.0.max(1), image_size.1.max(1));
+        let (area_width, area_height) = (area.width as u32, area.height as u32 * 2);
+
+        let width_ratio = area_width as f64 / image_width as f64;
+        let height_ratio = area_height as f64 / image_height as f64;
+
+        let scale_by = |ratio: f64| {
+            (
+                ((image_width as f64 * ratio).round() as u32).max(1),
+                ((image_height as f64 * ratio).round() as u32).max(1),
+            )
+        };
+
+        let scaled_size = match layout.fit {
+            FitMode::Fit => scale_by(width_ratio.min(height_ratio)),
+            FitMode::Fill => scale_by(width_ratio.max(height_ratio)),
+            FitMode::Stretch => (area_width.max(1), area_height.max(1)),
+            FitMode::Integer => {
+                let ratio = width_ratio.min(height_ratio);
+                if ratio >= 1.0 {
+                    scale_by(ratio.floor())
+                } else {
+                    scale_by(ratio)
+                }
+            }
+        };
+
+        let crop_size = (
+            scaled_size.0.min(area_width),
+            scaled_size.1.min(area_height),
+        );
+
+        let (horizontal, vertical) = layout.alignment.factors();
+
+        let crop_offset = (
+            ((scaled_size.0 - crop_size.0) as f32 * horizontal) as u32,
+            ((scaled_size.1 - crop_size.1) as f32 * vertical) as u32,
+        );
+
+        let render_width = crop_size.0 as u16;
+        let render_height = crop_size.1.div_ceil(2) as u16;
+
+        let render_area = Rect {
+            x: area.x + (area.width.saturating_sub(render_width) as f32 * horizontal) as u16,
+            y: area.y + (area.height.saturating_sub(render_height) as f32 * vertical) as u16,
+            width: render_width,
+            height: render_height,
+        };
+
+        Self {
+            scaled_size,
+            crop_offset,
+            crop_size,
+            render_area,
+            source_offset: Vec2::ZERO,
+            source_size: Vec2::ONE,
+        }
+    }
+
+    /// Sets the normalized region of the whole rendered image that the source image covers, so
+    /// that normalized positions refer to the whole rendered image.
+    pub fn with_source(mut self, source_offset: Vec2, source_size: Vec2) -> Self {
+        self.source_offset = source_offset;
+        self.source_size = source_size;
+        self
+    }
+
+    /// Maps the center of a buffer cell to a position in the rendered image, normalized so that
+    /// (0, 0) is the top left corner and (1, 1) is the bottom right corner. Returns `None` if the
+    /// cell is outside of the render area.
+    pub fn cell_to_normalized(&self, column: u16, row: u16) -> Option<Vec2> {
+        let render_area = self.render_area;
+
+        if !render_area.contains((column, row).into()) {
+            return None;
+        }
+
+        let x = (column - render_area.x) as f32 + 0.5 + self.crop_offset.0 as f32;
+        let y = ((row - render_area.y) as f32 + 0.5) * 2. + self.crop_offset.1 as f32;
+
+        let source_normalized =
+            Vec2::new(x / self.scaled_size.0 as f32, y / self.scaled_size.1 as f32);
+
+        Some(self.source_offset + source_normalized * self.source_size)
+    }
+
+    /// Maps a distance in buffer cells to a normalized distance in the rendered image.
+    pub fn cell_delta_to_normalized(&self, columns: i32, rows: i32) -> Vec2 {
+        let source_delta = Vec2::new(
+            columns as f32 / self.scaled_size.0 as f32,
+            rows as f32 * 2. / self.scaled_size.1 as f32,
+        );
+
+        source_delta * self.source_size
+    }
+
+    /// Maps a normalized position in the rendered image to the buffer cell it is drawn in. The
+    /// returned cell may be outside of the render area (or the buffer).
+    pub fn normalized_to_cell(&self, normalized: Vec2) -> (i32, i32) {
+        let normalized = (normalized - self.source_offset) / self.source_size;
+        let x = normalized.x * self.scaled_size.0 as f32 - self.crop_offset.0 as f32;
+        let y = normalized.y * self.scaled_size.1 as f32 - self.crop_offset.1 as f32;
+
+        (
+            self.render_area.x as i32 + x.floor() as i32,
+            self.render_area.y as i32 + (y / 2.).floor() as i32,
+        )
+    }
+
+    /// Resamples and crops an image according to this layout.
+    pub fn apply(&self, image: &DynamicImage, filter: ResizeFilter) -> DynamicImage {
+        let (scaled_width, scaled_height) = self.scaled_size;
+
+        let scaled_image = if image.dimensions() == self.scaled_size {
+            image.clone()
+        } else {
+            image.resize_exact(scaled_width, scaled_height, filter.into())
+        };
+
+        if self.crop_size == self.scaled_size {
+            scaled_image
+        } else {
+            let (crop_x, crop_y) = self.crop_offset;
+            let (crop_width, crop_height) = self.crop_size;
+            scaled_image.crop_imm(crop_x, crop_y, crop_width, crop_height)
+        }
+    }
+}
+
+/// Resizes the camera image (and the sobel image, if provided) to the given area according to
+/// the layout, returning the resized images and the area of the buffer they should be drawn in.
+/// The sobel image always uses nearest neighbor sampling so that edge directions are preserved.
+pub fn layout_images(
+    camera_image: &DynamicImage,
+    sobel_image: Option<&DynamicImage>,
+    area: Rect,
+    layout: &RatatuiCameraLayout,
+) -> (DynamicImage, Option<DynamicImage>, Rect) {
+    let image_layout = ImageLayout::new(camera_image.dimensions(), area, layout);
+
+    let camera_image = image_layout.apply(camera_image, layout.filter);
+    let sobel_image =
+        sobel_image.map(|sobel_image| image_layout.apply(sobel_image, ResizeFilter::Nearest));
+
+    (camera_image, sobel_image, image_layout.render_area)
+}
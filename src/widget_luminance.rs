@@ -1,181 +1,146 @@
-use bevy::color::Luminance;
-use image::imageops::FilterType;
-use image::{DynamicImage, GenericImageView};
+use std::sync::LazyLock;
+
+use bevy::color::{LinearRgba, Srgba};
+use image::DynamicImage;
 use ratatui::prelude::*;
-use ratatui::widgets::WidgetRef;
 
-use crate::{LuminanceConfig, RatatuiCameraEdgeDetection};
+use crate::widget_cells::{as_rgba8, rgba_quad, RatatuiCameraCell, RatatuiCameraCells};
+use crate::{LuminanceConfig, RatatuiCameraEdgeDetection, RatatuiCameraTransparency};
 
 pub struct RatatuiCameraWidgetLuminance<'a> {
     camera_image: &'a DynamicImage,
-    sobel_image: &'a Option<DynamicImage>,
+    sobel_image: Option<&'a DynamicImage>,
     strategy_config: &'a LuminanceConfig,
     edge_detection: &'a Option<RatatuiCameraEdgeDetection>,
+    transparency: &'a RatatuiCameraTransparency,
 }
 
 impl<'a> RatatuiCameraWidgetLuminance<'a> {
     pub fn new(
         camera_image: &'a DynamicImage,
-        sobel_image: &'a Option<DynamicImage>,
+        sobel_image: Option<&'a DynamicImage>,
         strategy_config: &'a LuminanceConfig,
         edge_detection: &'a Option<RatatuiCameraEdgeDetection>,
+        transparency: &'a RatatuiCameraTransparency,
     ) -> Self {
         Self {
             camera_image,
             sobel_image,
             strategy_config,
             edge_detection,
+            transparency,
         }
     }
-}
 
-impl WidgetRef for RatatuiCameraWidgetLuminance<'_> {
-    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+    /// Converts the (already resized) camera and sobel images into cells covering the render
+    /// area.
+    pub fn convert(&self, render_area: Rect) -> RatatuiCameraCells {
         let Self {
             camera_image,
             sobel_image,
             strategy_config,
             edge_detection,
+            transparency,
         } = self;
 
-        let camera_image = camera_image.resize(
-            area.width as u32,
-            area.height as u32 * 2,
-            FilterType::Nearest,
-        );
-
-        let render_area = Rect {
-            x: area.x + area.width.saturating_sub(camera_image.width() as u16) / 2,
-            y: area.y + (area.height).saturating_sub(camera_image.height() as u16 / 2) / 2,
-            width: camera_image.width() as u16,
-            height: camera_image.height() as u16 / 2,
-        };
+        let mut cells = RatatuiCameraCells::new(render_area);
 
-        let color_characters = convert_image_to_color_characters(
-            &camera_image,
-            &strategy_config.luminance_characters,
-            strategy_config.luminance_scale,
-        );
-
-        let sobel_image = sobel_image.as_ref().map(|sobel_image| {
-            sobel_image.resize(
-                area.width as u32,
-                area.height as u32 * 2,
-                FilterType::Nearest,
-            )
-        });
+        let camera_image = as_rgba8(camera_image);
+        let sobel_image = sobel_image.map(as_rgba8);
+        let character_selector = LuminanceCharacterSelector::new(strategy_config);
 
-        for (index, (mut character, mut color)) in color_characters.iter().enumerate() {
-            let x = index as u16 % camera_image.width() as u16;
-            let y = index as u16 / camera_image.width() as u16;
-            if x >= render_area.width || y >= render_area.height {
-                continue;
-            }
+        cells.convert_rows(|y, row| {
+            for (x, cell) in row.iter_mut().enumerate() {
+                let (x, y) = (x as u32, y as u32);
+                let Some(rgba) = rgba_quad(&camera_image, x, y) else {
+                    continue;
+                };
 
-            if let (Some(ref sobel_image), Some(edge_detection)) = (&sobel_image, edge_detection) {
-                if !sobel_image.in_bounds(x as u32, y as u32 * 2) {
+                if transparency.is_transparent(rgba[3]) {
+                    if **transparency == RatatuiCameraTransparency::Reset {
+                        *cell = RatatuiCameraCell::reset();
+                    }
                     continue;
                 }
 
-                let sobel_value = sobel_image.get_pixel(x as u32, y as u32 * 2);
-
-                match edge_detection.edge_characters {
-                    crate::EdgeCharacters::Directional {
-                        vertical,
-                        horizontal,
-                        forward_diagonal,
-                        backward_diagonal,
-                    } => {
-                        let is_max_sobel = |current: u8| {
-                            sobel_value
-                                .0
-                                .iter()
-                                .all(|val| (current > 0) && (current >= *val))
-                        };
-
-                        if is_max_sobel(sobel_value[0]) {
-                            character = vertical;
-                            color = edge_detection.edge_color.unwrap_or(color);
-                        } else if is_max_sobel(sobel_value[1]) {
-                            character = horizontal;
-                            color = edge_detection.edge_color.unwrap_or(color);
-                        } else if is_max_sobel(sobel_value[2]) {
-                            character = forward_diagonal;
-                            color = edge_detection.edge_color.unwrap_or(color);
-                        } else if is_max_sobel(sobel_value[3]) {
-                            character = backward_diagonal;
-                            color = edge_detection.edge_color.unwrap_or(color);
-                        }
-                    }
-                    crate::EdgeCharacters::Single(edge_character) => {
-                        if sobel_value.0.iter().any(|val| *val > 0) {
-                            character = edge_character;
-                            color = edge_detection.edge_color.unwrap_or(color);
-                        }
+                let mut character = character_selector.select(&rgba);
+                let mut color = Color::Rgb(rgba[0], rgba[1], rgba[2]);
+
+                if let (Some(sobel_image), Some(edge_detection)) = (&sobel_image, edge_detection) {
+                    let Some(sobel_value) = sobel_image.get_pixel_checked(x, y * 2) else {
+                        continue;
+                    };
+
+                    if let Some(edge_character) = edge_detection
+                        .edge_characters
+                        .for_sobel_value(sobel_value.0)
+                    {
+                        character = edge_character;
+                        color = edge_detection.edge_color.unwrap_or(color);
                     }
-                }
-            };
+                };
 
-            if let Some(cell) = buf.cell_mut((render_area.x + x, render_area.y + y)) {
-                cell.set_fg(color).set_char(character);
+                *cell = RatatuiCameraCell::new(character, color);
             }
-        }
+        });
+
+        cells
     }
 }
 
-fn convert_image_to_color_characters(
-    camera_image: &DynamicImage,
-    luminance_characters: &[char],
-    luminance_scale: f32,
-) -> Vec<(char, Color)> {
-    let rgb_triplets = convert_image_to_rgb_triplets(camera_image);
-    let characters = rgb_triplets
-        .iter()
-        .map(|rgb| convert_rgb_triplet_to_character(rgb, luminance_characters, luminance_scale));
-    let colors = rgb_triplets
-        .iter()
-        .map(|rgb| Color::Rgb(rgb[0], rgb[1], rgb[2]));
-
-    characters.zip(colors).collect()
+/// Maps 8-bit sRGB components to linear components scaled to `0..=65535`, so that luminance can
+/// be calculated with integer arithmetic.
+static SRGB_TO_LINEAR: LazyLock<[u32; 256]> = LazyLock::new(|| {
+    std::array::from_fn(|component| {
+        let linear = LinearRgba::from(Srgba::rgb_u8(component as u8, 0, 0)).red;
+        (linear * u16::MAX as f32).round() as u32
+    })
+});
+
+/// Rec. 709 luminance weights, scaled so that they sum to 65536.
+const LUMINANCE_WEIGHTS: [u32; 3] = [13933, 46871, 4732];
+
+/// The largest luminance scale used, at which the smallest nonzero luminance is already scaled
+/// to the maximum.
+const MAX_LUMINANCE_SCALE: f32 = 65536.0;
+
+/// Selects luminance characters using fixed-point arithmetic, which is much faster than converting
+/// each cell's color to linear floating point.
+struct LuminanceCharacterSelector<'a> {
+    luminance_characters: &'a [char],
+
+    /// The luminance scale in 16.16 fixed point. Scales above `MAX_LUMINANCE_SCALE` map any
+    /// nonzero luminance to the last character anyway, so they are clamped to keep the
+    /// multiplication from overflowing.
+    luminance_scale: u64,
 }
 
-fn convert_image_to_rgb_triplets(camera_image: &DynamicImage) -> Vec<[u8; 3]> {
-    let mut rgb_triplets =
-        vec![[0; 3]; (camera_image.width() * camera_image.height().div_ceil(2)) as usize];
-
-    for (y, row) in camera_image.to_rgb8().rows().enumerate() {
-        for (x, pixel) in row.enumerate() {
-            let position = x + (camera_image.width() as usize) * (y / 2);
-            if y % 2 == 0 {
-                rgb_triplets[position] = pixel.0;
-            } else {
-                rgb_triplets[position][0] =
-                    (rgb_triplets[position][0].saturating_add(pixel[0])) / 2;
-                rgb_triplets[position][1] =
-                    (rgb_triplets[position][1].saturating_add(pixel[1])) / 2;
-                rgb_triplets[position][2] =
-                    (rgb_triplets[position][2].saturating_add(pixel[2])) / 2;
-            }
+impl<'a> LuminanceCharacterSelector<'a> {
+    fn new(strategy_config: &'a LuminanceConfig) -> Self {
+        Self {
+            luminance_characters: &strategy_config.luminance_characters,
+            luminance_scale: (strategy_config
+                .luminance_scale
+                .clamp(0.0, MAX_LUMINANCE_SCALE)
+                * 65536.0) as u64,
         }
     }
 
-    rgb_triplets
-}
+    fn select(&self, rgba: &[u8; 4]) -> char {
+        let lut = &*SRGB_TO_LINEAR;
+        let luminance = (LUMINANCE_WEIGHTS[0] * lut[rgba[0] as usize]
+            + LUMINANCE_WEIGHTS[1] * lut[rgba[1] as usize]
+            + LUMINANCE_WEIGHTS[2] * lut[rgba[2] as usize])
+            >> 16;
+        let scaled_luminance = ((luminance as u64 * self.luminance_scale) >> 16).min(65535);
+        let character_count = self.luminance_characters.len() as u64;
+        let character_index =
+            ((scaled_luminance * character_count) >> 16).min(character_count.saturating_sub(1));
+
+        let Some(character) = self.luminance_characters.get(character_index as usize) else {
+            return ' ';
+        };
 
-fn convert_rgb_triplet_to_character(
-    rgb_triplet: &[u8; 3],
-    luminance_characters: &[char],
-    luminance_scale: f32,
-) -> char {
-    let luminance =
-        bevy::color::Color::srgb_u8(rgb_triplet[0], rgb_triplet[1], rgb_triplet[2]).luminance();
-    let scaled_luminance = (luminance * luminance_scale).min(1.0);
-    let character_index = ((scaled_luminance * luminance_characters.len() as f32) as usize)
-        .min(luminance_characters.len() - 1);
-
-    let Some(character) = luminance_characters.get(character_index) else {
-        return ' ';
-    };
-
-    *character
+        *character
+    }
 }
@@ -0,0 +1,56 @@
+use ratatui::prelude::*;
+
+use crate::camera_overlay::{ProjectedGlyph, ProjectedLabel};
+use crate::widget_layout::ImageLayout;
+
+/// Prints projected labels over the drawn image, centered on their projected cells and clamped
+/// to the render area. Labels are expected to be sorted farthest first.
+pub fn render_labels(labels: &[ProjectedLabel], image_layout: &ImageLayout, buf: &mut Buffer) {
+    let render_area = image_layout.render_area.intersection(buf.area);
+
+    if render_area.is_empty() {
+        return;
+    }
+
+    for label in labels {
+        let (column, row) = image_layout.normalized_to_cell(label.normalized);
+        let width = (Span::raw(&label.text).width() as i32).min(render_area.width as i32);
+
+        let x = (column - width / 2).clamp(
+            render_area.left() as i32,
+            render_area.right() as i32 - width,
+        );
+        let y = row.clamp(render_area.top() as i32, render_area.bottom() as i32 - 1);
+
+        buf.set_stringn(x as u16, y as u16, &label.text, width as usize, label.style);
+    }
+}
+
+/// Draws projected glyphs over the drawn image, skipping glyphs outside of the render area.
+/// Glyphs are expected to be sorted from lowest to highest priority.
+pub fn render_glyphs(glyphs: &[ProjectedGlyph], image_layout: &ImageLayout, buf: &mut Buffer) {
+    let render_area = image_layout.render_area.intersection(buf.area);
+
+    for glyph in glyphs {
+        let (column, row) = image_layout.normalized_to_cell(glyph.normalized);
+
+        let (Ok(column), Ok(row)) = (u16::try_from(column), u16::try_from(row)) else {
+            continue;
+        };
+
+        if !render_area.contains(Position::new(column, row)) {
+            continue;
+        }
+
+        let cell = &mut buf[(column, row)];
+        cell.set_char(glyph.character);
+
+        if let Some(fg) = glyph.fg {
+            cell.set_fg(fg);
+        }
+
+        if let Some(bg) = glyph.bg {
+            cell.set_bg(bg);
+        }
+    }
+}
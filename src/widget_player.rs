@@ -0,0 +1,321 @@
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use ratatui::prelude::{Buffer, Position, Rect};
+use ratatui::widgets::Widget;
+
+use crate::{RatatuiCameraCells, RatatuiCameraRecording};
+
+/// The fastest playback speed, which keeps scaled frame times finite.
+const MAX_SPEED: f32 = 1000.0;
+
+/// Add this plugin to advance every RatatuiCameraPlayer component by real time each frame. It does
+/// not depend on `RatatuiCameraPlugin` or on Bevy's rendering, so recordings can be played in apps
+/// that render nothing.
+///
+pub struct RatatuiCameraPlayerPlugin;
+
+impl Plugin for RatatuiCameraPlayerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreUpdate, advance_ratatui_camera_players_system);
+    }
+}
+
+/// Plays back a RatatuiCameraRecording at its recorded timing, without rendering anything with
+/// Bevy. Draw it like a RatatuiCameraWidget: the current frame's cells are drawn at the top left
+/// of the area, clipped to it.
+///
+/// As a component, the player is advanced automatically by the RatatuiCameraPlayerPlugin. Outside
+/// of an entity (or outside of Bevy), call `advance` with the elapsed time instead.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui::terminal::RatatuiContext;
+/// # use bevy_ratatui_camera::RatatuiCameraPlayer;
+/// #
+/// fn setup_system(mut commands: Commands) {
+///     commands.spawn(RatatuiCameraPlayer::load("cutscene.rccells").unwrap().with_looping(true));
+/// }
+///
+/// fn draw_system(
+///     mut ratatui: ResMut<RatatuiContext>,
+///     player: Query<&RatatuiCameraPlayer>,
+/// ) -> std::io::Result<()> {
+///     ratatui.draw(|frame| {
+///         frame.render_widget(player.single(), frame.area());
+///     })?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Component, Debug, Clone)]
+pub struct RatatuiCameraPlayer {
+    /// The recording being played.
+    pub recording: RatatuiCameraRecording,
+
+    position: Duration,
+    speed: f32,
+    paused: bool,
+    looping: bool,
+}
+
+impl RatatuiCameraPlayer {
+    /// Creates a player at the start of the recording, playing at normal speed.
+    pub fn new(recording: RatatuiCameraRecording) -> Self {
+        Self {
+            recording,
+            position: Duration::ZERO,
+            speed: 1.0,
+            paused: false,
+            looping: false,
+        }
+    }
+
+    /// Creates a player for a recording loaded from a file.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::new(RatatuiCameraRecording::load(path)?))
+    }
+
+    /// Sets whether playback restarts from the beginning after the last frame.
+    pub fn with_looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Sets the playback speed.
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.set_speed(speed);
+        self
+    }
+
+    /// Resumes playback.
+    pub fn play(&mut self) {
+        self.paused = false;
+    }
+
+    /// Pauses playback, keeping the current frame.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Pauses playback if playing, or resumes it if paused.
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    /// Whether playback is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Sets the playback speed, where 1.0 is the recorded speed. Negative and NaN speeds are
+    /// treated as 0.0, and speeds above 1000.0 (including infinity) as 1000.0.
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = if speed.is_nan() {
+            0.0
+        } else {
+            speed.clamp(0.0, MAX_SPEED)
+        };
+    }
+
+    /// The playback speed.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Jumps to a time in the recording, clamped to its duration.
+    pub fn seek(&mut self, position: Duration) {
+        self.position = position.min(self.recording.duration());
+    }
+
+    /// The current time in the recording.
+    pub fn position(&self) -> Duration {
+        self.position
+    }
+
+    /// Whether playback has reached the last frame (never true when looping).
+    pub fn is_finished(&self) -> bool {
+        !self.looping && self.position >= self.recording.duration()
+    }
+
+    /// Advances playback by an amount of real time, scaled by the playback speed. Does nothing
+    /// while paused.
+    pub fn advance(&mut self, delta: Duration) {
+        if self.paused {
+            return;
+        }
+
+        let duration = self.recording.duration();
+        let position = self.position + delta.mul_f32(self.speed);
+
+        self.position = match (self.looping, duration.is_zero()) {
+            (true, false) => {
+                Duration::from_nanos((position.as_nanos() % duration.as_nanos()) as u64)
+            }
+            _ => position.min(duration),
+        };
+    }
+
+    /// The cells of the frame at the current time.
+    pub fn cells(&self) -> Option<&RatatuiCameraCells> {
+        self.recording
+            .frame_at(self.position)
+            .map(|frame| &frame.cells)
+    }
+}
+
+impl Widget for &RatatuiCameraPlayer {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let Some(cells) = self.cells() else {
+            return;
+        };
+
+        let area = area.intersection(buf.area);
+
+        for (position, cell) in cells.iter() {
+            let x = area.x as u32 + (position.x - cells.area.x) as u32;
+            let y = area.y as u32 + (position.y - cells.area.y) as u32;
+
+            let (Ok(x), Ok(y)) = (u16::try_from(x), u16::try_from(y)) else {
+                continue;
+            };
+
+            let position = Position::new(x, y);
+            if area.contains(position) {
+                cell.apply(&mut buf[position]);
+            }
+        }
+    }
+}
+
+fn advance_ratatui_camera_players_system(
+    time: Res<Time<Real>>,
+    mut players: Query<&mut RatatuiCameraPlayer>,
+) {
+    for mut player in &mut players {
+        if !player.is_paused() && !player.is_finished() {
+            player.advance(time.delta());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RatatuiCameraCell, RatatuiCameraRecordedFrame};
+
+    /// A player for one-cell frames at 0, 100 and 200 milliseconds, printing 'a', 'b' and 'c'.
+    fn player() -> RatatuiCameraPlayer {
+        let frames = ['a', 'b', 'c']
+            .into_iter()
+            .enumerate()
+            .map(|(index, symbol)| {
+                let mut cells = RatatuiCameraCells::new(Rect::new(0, 0, 1, 1));
+                cells.cells[0] = RatatuiCameraCell::new(symbol, ratatui::style::Color::White);
+
+                RatatuiCameraRecordedFrame {
+                    time: Duration::from_millis(index as u64 * 100),
+                    cells,
+                }
+            })
+            .collect();
+
+        RatatuiCameraPlayer::new(RatatuiCameraRecording { frames })
+    }
+
+    fn symbol(player: &RatatuiCameraPlayer) -> Option<char> {
+        player.cells()?.cells[0].symbol
+    }
+
+    /// The player's position in whole milliseconds, as scaling by the speed is not exact.
+    fn millis(player: &RatatuiCameraPlayer) -> u64 {
+        (player.position().as_secs_f64() * 1000.0).round() as u64
+    }
+
+    #[test]
+    fn advance() {
+        let mut player = player();
+        assert_eq!(symbol(&player), Some('a'));
+
+        player.advance(Duration::from_millis(150));
+        assert_eq!(symbol(&player), Some('b'));
+
+        player.advance(Duration::from_millis(500));
+        assert_eq!(millis(&player), 200);
+        assert_eq!(symbol(&player), Some('c'));
+        assert!(player.is_finished());
+    }
+
+    #[test]
+    fn pause_and_speed() {
+        let mut player = player().with_speed(2.0);
+
+        player.pause();
+        player.advance(Duration::from_millis(50));
+        assert_eq!(millis(&player), 0);
+
+        player.play();
+        player.advance(Duration::from_millis(50));
+        assert_eq!(millis(&player), 100);
+        assert_eq!(symbol(&player), Some('b'));
+    }
+
+    #[test]
+    fn invalid_speeds() {
+        let mut player = player();
+
+        for (speed, expected) in [
+            (-1.0, 0.0),
+            (f32::NAN, 0.0),
+            (f32::INFINITY, MAX_SPEED),
+            (1e9, MAX_SPEED),
+        ] {
+            player.set_speed(speed);
+            assert_eq!(player.speed(), expected);
+            player.advance(Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn seek() {
+        let mut player = player();
+
+        player.seek(Duration::from_millis(120));
+        assert_eq!(symbol(&player), Some('b'));
+
+        player.seek(Duration::from_secs(10));
+        assert_eq!(millis(&player), 200);
+        assert!(player.is_finished());
+    }
+
+    #[test]
+    fn looping() {
+        let mut player = player().with_looping(true);
+
+        player.advance(Duration::from_millis(250));
+        assert_eq!(millis(&player), 50);
+        assert_eq!(symbol(&player), Some('a'));
+        assert!(!player.is_finished());
+
+        player.advance(Duration::from_millis(150));
+        assert_eq!(millis(&player), 0);
+        assert_eq!(symbol(&player), Some('a'));
+    }
+
+    #[test]
+    fn render() {
+        let mut player = player();
+        player.seek(Duration::from_millis(200));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        player.render(Rect::new(1, 1, 2, 1), &mut buffer);
+
+        assert_eq!(buffer[(1, 1)].symbol(), "c");
+        assert_eq!(buffer[(0, 0)].symbol(), " ");
+    }
+}
@@ -0,0 +1,421 @@
+use std::io::Write;
+use std::time::Duration;
+
+use bevy::utils::HashMap;
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{imageops, Delay, Frame, ImageResult, Rgba, RgbaImage};
+use ratatui::prelude::*;
+
+use crate::widget_ansi::{color_to_rgb, ResolvedCell, DEFAULT_BG, DEFAULT_FG};
+use crate::widget_raster_font::{ASCII_GLYPHS, BOX_DRAWING_ARMS};
+use crate::RatatuiCameraCells;
+
+/// Width of a cell in pixels, before scaling.
+const GLYPH_WIDTH: u32 = 8;
+
+/// Height of a cell in pixels, before scaling.
+const GLYPH_HEIGHT: u32 = 16;
+
+/// The pixels of a glyph, one row per element, where the least significant bit of each row is the
+/// leftmost pixel.
+type GlyphMask = [u8; GLYPH_HEIGHT as usize];
+
+/// Renders cells to images using a bundled monospace bitmap font, for generating screenshots and
+/// animations of converted frames without a terminal. Each cell is 8x16 pixels (multiplied by the
+/// scale), and the font covers printable ASCII, block elements, braille patterns, and box drawing
+/// characters. Other characters are drawn as `'?'`.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy_ratatui_camera::{RatatuiCameraRasterizer, RatatuiCameraWidget};
+/// # fn export(widget: &RatatuiCameraWidget) -> image::ImageResult<()> {
+/// let cells = widget.cells(ratatui::layout::Rect::new(0, 0, 80, 24));
+/// RatatuiCameraRasterizer::default()
+///     .with_scale(2)
+///     .rasterize(&cells)
+///     .save("frame.png")?;
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RatatuiCameraRasterizer {
+    /// Factor that each cell's size in pixels is multiplied by.
+    pub scale: u32,
+}
+
+impl Default for RatatuiCameraRasterizer {
+    fn default() -> Self {
+        Self { scale: 1 }
+    }
+}
+
+impl RatatuiCameraRasterizer {
+    /// Sets the scale.
+    pub fn with_scale(mut self, scale: u32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Renders cells to an image. Empty cell properties are drawn as a blank space in the default
+    /// colors (light gray on black).
+    pub fn rasterize(&self, cells: &RatatuiCameraCells) -> RgbaImage {
+        let scale = self.scale.max(1);
+        let (cell_width, cell_height) = (GLYPH_WIDTH * scale, GLYPH_HEIGHT * scale);
+        let mut image = RgbaImage::new(
+            cells.area.width as u32 * cell_width,
+            cells.area.height as u32 * cell_height,
+        );
+
+        let mut glyph_masks: HashMap<char, GlyphMask> = HashMap::default();
+
+        for (index, cell) in cells.cells.iter().enumerate() {
+            let cell = ResolvedCell::from(cell);
+            let modifier = cell.style.modifier;
+
+            let (mut fg, mut bg) = (
+                color_to_rgb(cell.style.fg, DEFAULT_FG),
+                color_to_rgb(cell.style.bg, DEFAULT_BG),
+            );
+
+            if modifier.contains(Modifier::REVERSED) {
+                (fg, bg) = (bg, fg);
+            }
+
+            if modifier.contains(Modifier::DIM) {
+                fg = std::array::from_fn(|channel| {
+                    ((fg[channel] as u16 + bg[channel] as u16) / 2) as u8
+                });
+            }
+
+            let mut mask = if modifier.contains(Modifier::HIDDEN) {
+                GlyphMask::default()
+            } else {
+                *glyph_masks
+                    .entry(cell.symbol)
+                    .or_insert_with(|| glyph_mask(cell.symbol))
+            };
+
+            if modifier.contains(Modifier::BOLD) {
+                mask = mask.map(|row| row | (row << 1));
+            }
+
+            if modifier.contains(Modifier::UNDERLINED) {
+                mask[GLYPH_HEIGHT as usize - 1] = u8::MAX;
+            }
+
+            if modifier.contains(Modifier::CROSSED_OUT) {
+                mask[GLYPH_HEIGHT as usize / 2] = u8::MAX;
+            }
+
+            let column = index as u32 % cells.area.width as u32;
+            let row = index as u32 / cells.area.width as u32;
+
+            for y in 0..cell_height {
+                let mask_row = mask[(y / scale) as usize];
+
+                for x in 0..cell_width {
+                    let is_set = mask_row & (1 << (x / scale)) != 0;
+                    let [r, g, b] = if is_set { fg } else { bg };
+
+                    image.put_pixel(
+                        column * cell_width + x,
+                        row * cell_height + y,
+                        Rgba([r, g, b, u8::MAX]),
+                    );
+                }
+            }
+        }
+
+        image
+    }
+
+    /// Encodes a looping animated GIF of frames of cells, each shown for the given duration. The
+    /// GIF is sized to the first frame; later frames of a different size are cropped or padded.
+    pub fn encode_gif<'a, W: Write>(
+        &self,
+        writer: W,
+        frames: impl IntoIterator<Item = (&'a RatatuiCameraCells, Duration)>,
+    ) -> ImageResult<()> {
+        let mut encoder = GifEncoder::new_with_speed(writer, 10);
+        encoder.set_repeat(Repeat::Infinite)?;
+
+        let mut size: Option<(u32, u32)> = None;
+
+        for (cells, duration) in frames {
+            let mut image = self.rasterize(cells);
+            let (width, height) = *size.get_or_insert(image.dimensions());
+
+            if image.dimensions() != (width, height) {
+                let mut resized_image =
+                    RgbaImage::from_pixel(width, height, Rgba([0, 0, 0, u8::MAX]));
+                imageops::replace(&mut resized_image, &image, 0, 0);
+                image = resized_image;
+            }
+
+            encoder.encode_frame(Frame::from_parts(
+                image,
+                0,
+                0,
+                Delay::from_saturating_duration(duration),
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The pixels of a character's glyph.
+fn glyph_mask(character: char) -> GlyphMask {
+    match character as u32 {
+        0x20..=0x7E => ascii_mask(character),
+        code @ 0x2500..=0x257F => box_drawing_mask(code),
+        code @ 0x2580..=0x259F => block_element_mask(code),
+        code @ 0x2800..=0x28FF => braille_mask(code as u8),
+        _ => ascii_mask('?'),
+    }
+}
+
+fn mask_from_fn(is_set: impl Fn(u32, u32) -> bool) -> GlyphMask {
+    std::array::from_fn(|y| {
+        (0..GLYPH_WIDTH).fold(0, |row, x| {
+            if is_set(x, y as u32) {
+                row | (1 << x)
+            } else {
+                row
+            }
+        })
+    })
+}
+
+/// The 8x8 ASCII glyph, stretched to twice its height.
+fn ascii_mask(character: char) -> GlyphMask {
+    let glyph = ASCII_GLYPHS[character as usize - 0x20];
+    std::array::from_fn(|y| glyph[y / 2])
+}
+
+/// Block elements (U+2580 to U+259F): partial blocks, shades, and quadrants.
+fn block_element_mask(code: u32) -> GlyphMask {
+    let (upper_left, upper_right, lower_left, lower_right) = (0b0001, 0b0010, 0b0100, 0b1000);
+
+    let quadrants = |set: u8| {
+        mask_from_fn(move |x, y| {
+            let quadrant = match (x < GLYPH_WIDTH / 2, y < GLYPH_HEIGHT / 2) {
+                (true, true) => upper_left,
+                (false, true) => upper_right,
+                (true, false) => lower_left,
+                (false, false) => lower_right,
+            };
+            set & quadrant != 0
+        })
+    };
+
+    match code {
+        0x2580 => mask_from_fn(|_, y| y < GLYPH_HEIGHT / 2),
+        0x2581..=0x2588 => {
+            let eighths = code - 0x2580;
+            mask_from_fn(move |_, y| y >= GLYPH_HEIGHT - eighths * GLYPH_HEIGHT / 8)
+        }
+        0x2589..=0x258F => {
+            let eighths = 0x2590 - code;
+            mask_from_fn(move |x, _| x < eighths * GLYPH_WIDTH / 8)
+        }
+        0x2590 => mask_from_fn(|x, _| x >= GLYPH_WIDTH / 2),
+        0x2591 => mask_from_fn(|x, y| x % 2 == 0 && y % 2 == 0),
+        0x2592 => mask_from_fn(|x, y| (x + y) % 2 == 0),
+        0x2593 => mask_from_fn(|x, y| !(x % 2 == 1 && y % 2 == 1)),
+        0x2594 => mask_from_fn(|_, y| y < GLYPH_HEIGHT / 8),
+        0x2595 => mask_from_fn(|x, _| x >= GLYPH_WIDTH - GLYPH_WIDTH / 8),
+        0x2596 => quadrants(lower_left),
+        0x2597 => quadrants(lower_right),
+        0x2598 => quadrants(upper_left),
+        0x2599 => quadrants(upper_left | lower_left | lower_right),
+        0x259A => quadrants(upper_left | lower_right),
+        0x259B => quadrants(upper_left | upper_right | lower_left),
+        0x259C => quadrants(upper_left | upper_right | lower_right),
+        0x259D => quadrants(upper_right),
+        0x259E => quadrants(upper_right | lower_left),
+        _ => quadrants(upper_right | lower_left | lower_right),
+    }
+}
+
+/// Braille patterns (U+2800 to U+28FF), where each bit of the code point's low byte is a dot.
+fn braille_mask(dots: u8) -> GlyphMask {
+    // (column, row) of the dot for each bit, from least to most significant.
+    const DOT_POSITIONS: [(u32, u32); 8] = [
+        (0, 0),
+        (0, 1),
+        (0, 2),
+        (1, 0),
+        (1, 1),
+        (1, 2),
+        (0, 3),
+        (1, 3),
+    ];
+
+    mask_from_fn(|x, y| {
+        DOT_POSITIONS
+            .iter()
+            .enumerate()
+            .any(|(bit, &(column, row))| {
+                let (dot_x, dot_y) = (1 + column * 4, 1 + row * 4);
+                dots & (1 << bit) != 0
+                    && (dot_x..dot_x + 2).contains(&x)
+                    && (dot_y..dot_y + 2).contains(&y)
+            })
+    })
+}
+
+/// Box drawing characters (U+2500 to U+257F), drawn as arms from the center of the cell.
+fn box_drawing_mask(code: u32) -> GlyphMask {
+    let (center_x, center_y) = (GLYPH_WIDTH / 2 - 1, GLYPH_HEIGHT / 2 - 1);
+
+    match code {
+        0x2571 => return mask_from_fn(|x, y| x == (GLYPH_HEIGHT - 1 - y) / 2),
+        0x2572 => return mask_from_fn(|x, y| x == y / 2),
+        0x2573 => return mask_from_fn(|x, y| x == (GLYPH_HEIGHT - 1 - y) / 2 || x == y / 2),
+        _ => {}
+    }
+
+    let [left, up, right, down] = BOX_DRAWING_ARMS[(code - 0x2500) as usize];
+
+    // Whether a pixel at the given offset across an arm is part of a line of the given weight.
+    let is_on_line = |weight: u8, offset: u32, center: u32| match weight {
+        1 => offset == center,
+        2 => offset == center || offset == center + 1,
+        3 => offset == center - 1 || offset == center + 2,
+        _ => false,
+    };
+
+    mask_from_fn(|x, y| {
+        (x <= center_x + 1 && is_on_line(left, y, center_y))
+            || (x >= center_x && is_on_line(right, y, center_y))
+            || (y <= center_y + 1 && is_on_line(up, x, center_x))
+            || (y >= center_y && is_on_line(down, x, center_x))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use image::codecs::gif::GifDecoder;
+    use image::AnimationDecoder;
+
+    use super::*;
+    use crate::RatatuiCameraCell;
+
+    fn cells(area: Rect, symbols: &[char]) -> RatatuiCameraCells {
+        let mut cells = RatatuiCameraCells::new(area);
+        for (cell, symbol) in cells.cells.iter_mut().zip(symbols) {
+            *cell = RatatuiCameraCell {
+                bg: Some(Color::Blue),
+                ..RatatuiCameraCell::new(*symbol, Color::Red)
+            };
+        }
+        cells
+    }
+
+    const FG: Rgba<u8> = Rgba([205, 0, 0, 255]);
+    const BG: Rgba<u8> = Rgba([0, 0, 238, 255]);
+
+    #[test]
+    fn dimensions() {
+        let cells = cells(Rect::new(5, 5, 3, 2), &[]);
+
+        assert_eq!(
+            RatatuiCameraRasterizer::default()
+                .rasterize(&cells)
+                .dimensions(),
+            (24, 32)
+        );
+        assert_eq!(
+            RatatuiCameraRasterizer::default()
+                .with_scale(3)
+                .rasterize(&cells)
+                .dimensions(),
+            (72, 96)
+        );
+        assert_eq!(
+            RatatuiCameraRasterizer::default()
+                .with_scale(0)
+                .rasterize(&cells)
+                .dimensions(),
+            (24, 32)
+        );
+    }
+
+    #[test]
+    fn cells_are_placed_in_order() {
+        let image = RatatuiCameraRasterizer::default()
+            .with_scale(2)
+            .rasterize(&cells(Rect::new(0, 0, 2, 2), &[' ', '█', '▀', '▐']));
+
+        // Blank, full block, upper half block, and right half block.
+        assert_eq!(*image.get_pixel(8, 16), BG);
+        assert_eq!(*image.get_pixel(16, 0), FG);
+        assert_eq!(*image.get_pixel(31, 31), FG);
+        assert_eq!(*image.get_pixel(0, 32), FG);
+        assert_eq!(*image.get_pixel(0, 63), BG);
+        assert_eq!(*image.get_pixel(16, 40), BG);
+        assert_eq!(*image.get_pixel(31, 40), FG);
+    }
+
+    #[test]
+    fn modifiers() {
+        let mut cells = cells(Rect::new(0, 0, 2, 1), &['█', ' ']);
+        cells.cells[0].modifier = Modifier::REVERSED;
+        cells.cells[1].modifier = Modifier::UNDERLINED;
+
+        let image = RatatuiCameraRasterizer::default().rasterize(&cells);
+
+        assert_eq!(*image.get_pixel(0, 0), BG);
+        assert_eq!(*image.get_pixel(8, 0), BG);
+        assert_eq!(*image.get_pixel(8, 15), FG);
+    }
+
+    #[test]
+    fn box_drawing() {
+        let mask = |character| glyph_mask(character).map(|row| row.count_ones());
+
+        // A light horizontal line is a single full-width row, and a heavy vertical line is two
+        // columns down the whole height.
+        let horizontal = mask('─');
+        assert_eq!(horizontal.iter().filter(|count| **count == 8).count(), 1);
+        assert_eq!(horizontal.iter().sum::<u32>(), 8);
+        assert_eq!(mask('┃'), [2; GLYPH_HEIGHT as usize]);
+        assert_ne!(mask('╿'), mask('╼'));
+    }
+
+    #[test]
+    fn gif_frames() {
+        let first = cells(Rect::new(0, 0, 2, 1), &['a', 'b']);
+        let second = cells(Rect::new(0, 0, 3, 2), &['c']);
+        let frame_duration = Duration::from_millis(100);
+
+        let mut gif = Vec::new();
+        RatatuiCameraRasterizer::default()
+            .encode_gif(
+                &mut gif,
+                [
+                    (&first, frame_duration),
+                    (&second, frame_duration),
+                    (&first, frame_duration),
+                ],
+            )
+            .unwrap();
+
+        let frames = GifDecoder::new(Cursor::new(gif))
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap();
+
+        assert_eq!(frames.len(), 3);
+        for frame in &frames {
+            assert_eq!(frame.buffer().dimensions(), (16, 16));
+            assert_eq!(Duration::from(frame.delay()), frame_duration);
+        }
+    }
+}
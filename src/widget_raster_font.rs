@@ -0,0 +1,234 @@
+/// An 8x8 monospace bitmap font covering printable ASCII (`' '` to `'~'`), from the public domain
+/// font8x8 by Daniel Hepper (based on the IBM PC BIOS font). Each glyph is eight rows from top to
+/// bottom, where the least significant bit of each row is the leftmost pixel.
+pub(crate) const ASCII_GLYPHS: [[u8; 8]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // ' '
+    [0x18, 0x3C, 0x3C, 0x18, 0x18, 0x00, 0x18, 0x00], // '!'
+    [0x36, 0x36, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '"'
+    [0x36, 0x36, 0x7F, 0x36, 0x7F, 0x36, 0x36, 0x00], // '#'
+    [0x0C, 0x3E, 0x03, 0x1E, 0x30, 0x1F, 0x0C, 0x00], // '$'
+    [0x00, 0x63, 0x33, 0x18, 0x0C, 0x66, 0x63, 0x00], // '%'
+    [0x1C, 0x36, 0x1C, 0x6E, 0x3B, 0x33, 0x6E, 0x00], // '&'
+    [0x06, 0x06, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00], // "'"
+    [0x18, 0x0C, 0x06, 0x06, 0x06, 0x0C, 0x18, 0x00], // '('
+    [0x06, 0x0C, 0x18, 0x18, 0x18, 0x0C, 0x06, 0x00], // ')'
+    [0x00, 0x66, 0x3C, 0xFF, 0x3C, 0x66, 0x00, 0x00], // '*'
+    [0x00, 0x0C, 0x0C, 0x3F, 0x0C, 0x0C, 0x00, 0x00], // '+'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C, 0x06], // ','
+    [0x00, 0x00, 0x00, 0x3F, 0x00, 0x00, 0x00, 0x00], // '-'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C, 0x00], // '.'
+    [0x60, 0x30, 0x18, 0x0C, 0x06, 0x03, 0x01, 0x00], // '/'
+    [0x3E, 0x63, 0x73, 0x7B, 0x6F, 0x67, 0x3E, 0x00], // '0'
+    [0x0C, 0x0E, 0x0C, 0x0C, 0x0C, 0x0C, 0x3F, 0x00], // '1'
+    [0x1E, 0x33, 0x30, 0x1C, 0x06, 0x33, 0x3F, 0x00], // '2'
+    [0x1E, 0x33, 0x30, 0x1C, 0x30, 0x33, 0x1E, 0x00], // '3'
+    [0x38, 0x3C, 0x36, 0x33, 0x7F, 0x30, 0x78, 0x00], // '4'
+    [0x3F, 0x03, 0x1F, 0x30, 0x30, 0x33, 0x1E, 0x00], // '5'
+    [0x1C, 0x06, 0x03, 0x1F, 0x33, 0x33, 0x1E, 0x00], // '6'
+    [0x3F, 0x33, 0x30, 0x18, 0x0C, 0x0C, 0x0C, 0x00], // '7'
+    [0x1E, 0x33, 0x33, 0x1E, 0x33, 0x33, 0x1E, 0x00], // '8'
+    [0x1E, 0x33, 0x33, 0x3E, 0x30, 0x18, 0x0E, 0x00], // '9'
+    [0x00, 0x0C, 0x0C, 0x00, 0x00, 0x0C, 0x0C, 0x00], // ':'
+    [0x00, 0x0C, 0x0C, 0x00, 0x00, 0x0C, 0x0C, 0x06], // ';'
+    [0x18, 0x0C, 0x06, 0x03, 0x06, 0x0C, 0x18, 0x00], // '<'
+    [0x00, 0x00, 0x3F, 0x00, 0x00, 0x3F, 0x00, 0x00], // '='
+    [0x06, 0x0C, 0x18, 0x30, 0x18, 0x0C, 0x06, 0x00], // '>'
+    [0x1E, 0x33, 0x30, 0x18, 0x0C, 0x00, 0x0C, 0x00], // '?'
+    [0x3E, 0x63, 0x7B, 0x7B, 0x7B, 0x03, 0x1E, 0x00], // '@'
+    [0x0C, 0x1E, 0x33, 0x33, 0x3F, 0x33, 0x33, 0x00], // 'A'
+    [0x3F, 0x66, 0x66, 0x3E, 0x66, 0x66, 0x3F, 0x00], // 'B'
+    [0x3C, 0x66, 0x03, 0x03, 0x03, 0x66, 0x3C, 0x00], // 'C'
+    [0x1F, 0x36, 0x66, 0x66, 0x66, 0x36, 0x1F, 0x00], // 'D'
+    [0x7F, 0x46, 0x16, 0x1E, 0x16, 0x46, 0x7F, 0x00], // 'E'
+    [0x7F, 0x46, 0x16, 0x1E, 0x16, 0x06, 0x0F, 0x00], // 'F'
+    [0x3C, 0x66, 0x03, 0x03, 0x73, 0x66, 0x7C, 0x00], // 'G'
+    [0x33, 0x33, 0x33, 0x3F, 0x33, 0x33, 0x33, 0x00], // 'H'
+    [0x1E, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x1E, 0x00], // 'I'
+    [0x78, 0x30, 0x30, 0x30, 0x33, 0x33, 0x1E, 0x00], // 'J'
+    [0x67, 0x66, 0x36, 0x1E, 0x36, 0x66, 0x67, 0x00], // 'K'
+    [0x0F, 0x06, 0x06, 0x06, 0x46, 0x66, 0x7F, 0x00], // 'L'
+    [0x63, 0x77, 0x7F, 0x7F, 0x6B, 0x63, 0x63, 0x00], // 'M'
+    [0x63, 0x67, 0x6F, 0x7B, 0x73, 0x63, 0x63, 0x00], // 'N'
+    [0x1C, 0x36, 0x63, 0x63, 0x63, 0x36, 0x1C, 0x00], // 'O'
+    [0x3F, 0x66, 0x66, 0x3E, 0x06, 0x06, 0x0F, 0x00], // 'P'
+    [0x1E, 0x33, 0x33, 0x33, 0x3B, 0x1E, 0x38, 0x00], // 'Q'
+    [0x3F, 0x66, 0x66, 0x3E, 0x36, 0x66, 0x67, 0x00], // 'R'
+    [0x1E, 0x33, 0x07, 0x0E, 0x38, 0x33, 0x1E, 0x00], // 'S'
+    [0x3F, 0x2D, 0x0C, 0x0C, 0x0C, 0x0C, 0x1E, 0x00], // 'T'
+    [0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x3F, 0x00], // 'U'
+    [0x33, 0x33, 0x33, 0x33, 0x33, 0x1E, 0x0C, 0x00], // 'V'
+    [0x63, 0x63, 0x63, 0x6B, 0x7F, 0x77, 0x63, 0x00], // 'W'
+    [0x63, 0x63, 0x36, 0x1C, 0x1C, 0x36, 0x63, 0x00], // 'X'
+    [0x33, 0x33, 0x33, 0x1E, 0x0C, 0x0C, 0x1E, 0x00], // 'Y'
+    [0x7F, 0x63, 0x31, 0x18, 0x4C, 0x66, 0x7F, 0x00], // 'Z'
+    [0x1E, 0x06, 0x06, 0x06, 0x06, 0x06, 0x1E, 0x00], // '['
+    [0x03, 0x06, 0x0C, 0x18, 0x30, 0x60, 0x40, 0x00], // '\\'
+    [0x1E, 0x18, 0x18, 0x18, 0x18, 0x18, 0x1E, 0x00], // ']'
+    [0x08, 0x1C, 0x36, 0x63, 0x00, 0x00, 0x00, 0x00], // '^'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF], // '_'
+    [0x0C, 0x0C, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00], // '`'
+    [0x00, 0x00, 0x1E, 0x30, 0x3E, 0x33, 0x6E, 0x00], // 'a'
+    [0x07, 0x06, 0x06, 0x3E, 0x66, 0x66, 0x3B, 0x00], // 'b'
+    [0x00, 0x00, 0x1E, 0x33, 0x03, 0x33, 0x1E, 0x00], // 'c'
+    [0x38, 0x30, 0x30, 0x3E, 0x33, 0x33, 0x6E, 0x00], // 'd'
+    [0x00, 0x00, 0x1E, 0x33, 0x3F, 0x03, 0x1E, 0x00], // 'e'
+    [0x1C, 0x36, 0x06, 0x0F, 0x06, 0x06, 0x0F, 0x00], // 'f'
+    [0x00, 0x00, 0x6E, 0x33, 0x33, 0x3E, 0x30, 0x1F], // 'g'
+    [0x07, 0x06, 0x36, 0x6E, 0x66, 0x66, 0x67, 0x00], // 'h'
+    [0x0C, 0x00, 0x0E, 0x0C, 0x0C, 0x0C, 0x1E, 0x00], // 'i'
+    [0x30, 0x00, 0x30, 0x30, 0x30, 0x33, 0x33, 0x1E], // 'j'
+    [0x07, 0x06, 0x66, 0x36, 0x1E, 0x36, 0x67, 0x00], // 'k'
+    [0x0E, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x1E, 0x00], // 'l'
+    [0x00, 0x00, 0x33, 0x7F, 0x7F, 0x6B, 0x63, 0x00], // 'm'
+    [0x00, 0x00, 0x1F, 0x33, 0x33, 0x33, 0x33, 0x00], // 'n'
+    [0x00, 0x00, 0x1E, 0x33, 0x33, 0x33, 0x1E, 0x00], // 'o'
+    [0x00, 0x00, 0x3B, 0x66, 0x66, 0x3E, 0x06, 0x0F], // 'p'
+    [0x00, 0x00, 0x6E, 0x33, 0x33, 0x3E, 0x30, 0x78], // 'q'
+    [0x00, 0x00, 0x3B, 0x6E, 0x66, 0x06, 0x0F, 0x00], // 'r'
+    [0x00, 0x00, 0x3E, 0x03, 0x1E, 0x30, 0x1F, 0x00], // 's'
+    [0x08, 0x0C, 0x3E, 0x0C, 0x0C, 0x2C, 0x18, 0x00], // 't'
+    [0x00, 0x00, 0x33, 0x33, 0x33, 0x33, 0x6E, 0x00], // 'u'
+    [0x00, 0x00, 0x33, 0x33, 0x33, 0x1E, 0x0C, 0x00], // 'v'
+    [0x00, 0x00, 0x63, 0x6B, 0x7F, 0x7F, 0x36, 0x00], // 'w'
+    [0x00, 0x00, 0x63, 0x36, 0x1C, 0x36, 0x63, 0x00], // 'x'
+    [0x00, 0x00, 0x33, 0x33, 0x33, 0x3E, 0x30, 0x1F], // 'y'
+    [0x00, 0x00, 0x3F, 0x19, 0x0C, 0x26, 0x3F, 0x00], // 'z'
+    [0x38, 0x0C, 0x0C, 0x07, 0x0C, 0x0C, 0x38, 0x00], // '{'
+    [0x18, 0x18, 0x18, 0x00, 0x18, 0x18, 0x18, 0x00], // '|'
+    [0x07, 0x0C, 0x0C, 0x38, 0x0C, 0x0C, 0x07, 0x00], // '}'
+    [0x6E, 0x3B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '~'
+];
+
+/// The arms of each box drawing character (U+2500 to U+257F) as `[left, up, right, down]`, where
+/// 0 is no arm, 1 is a light line, 2 is a heavy line, and 3 is a double line. Dashed lines and
+/// arcs are drawn as solid lines and corners, and the diagonals (U+2571 to U+2573) have no arms.
+pub(crate) const BOX_DRAWING_ARMS: [[u8; 4]; 128] = [
+    [1, 0, 1, 0], // U+2500 '─'
+    [2, 0, 2, 0], // U+2501 '━'
+    [0, 1, 0, 1], // U+2502 '│'
+    [0, 2, 0, 2], // U+2503 '┃'
+    [1, 0, 1, 0], // U+2504 '┄'
+    [2, 0, 2, 0], // U+2505 '┅'
+    [0, 1, 0, 1], // U+2506 '┆'
+    [0, 2, 0, 2], // U+2507 '┇'
+    [1, 0, 1, 0], // U+2508 '┈'
+    [2, 0, 2, 0], // U+2509 '┉'
+    [0, 1, 0, 1], // U+250A '┊'
+    [0, 2, 0, 2], // U+250B '┋'
+    [0, 0, 1, 1], // U+250C '┌'
+    [0, 0, 2, 1], // U+250D '┍'
+    [0, 0, 1, 2], // U+250E '┎'
+    [0, 0, 2, 2], // U+250F '┏'
+    [1, 0, 0, 1], // U+2510 '┐'
+    [2, 0, 0, 1], // U+2511 '┑'
+    [1, 0, 0, 2], // U+2512 '┒'
+    [2, 0, 0, 2], // U+2513 '┓'
+    [0, 1, 1, 0], // U+2514 '└'
+    [0, 1, 2, 0], // U+2515 '┕'
+    [0, 2, 1, 0], // U+2516 '┖'
+    [0, 2, 2, 0], // U+2517 '┗'
+    [1, 1, 0, 0], // U+2518 '┘'
+    [2, 1, 0, 0], // U+2519 '┙'
+    [1, 2, 0, 0], // U+251A '┚'
+    [2, 2, 0, 0], // U+251B '┛'
+    [0, 1, 1, 1], // U+251C '├'
+    [0, 1, 2, 1], // U+251D '┝'
+    [0, 2, 1, 1], // U+251E '┞'
+    [0, 1, 1, 2], // U+251F '┟'
+    [0, 2, 1, 2], // U+2520 '┠'
+    [0, 2, 2, 1], // U+2521 '┡'
+    [0, 1, 2, 2], // U+2522 '┢'
+    [0, 2, 2, 2], // U+2523 '┣'
+    [1, 1, 0, 1], // U+2524 '┤'
+    [2, 1, 0, 1], // U+2525 '┥'
+    [1, 2, 0, 1], // U+2526 '┦'
+    [1, 1, 0, 2], // U+2527 '┧'
+    [1, 2, 0, 2], // U+2528 '┨'
+    [2, 2, 0, 1], // U+2529 '┩'
+    [2, 1, 0, 2], // U+252A '┪'
+    [2, 2, 0, 2], // U+252B '┫'
+    [1, 0, 1, 1], // U+252C '┬'
+    [2, 0, 1, 1], // U+252D '┭'
+    [1, 0, 2, 1], // U+252E '┮'
+    [2, 0, 2, 1], // U+252F '┯'
+    [1, 0, 1, 2], // U+2530 '┰'
+    [2, 0, 1, 2], // U+2531 '┱'
+    [1, 0, 2, 2], // U+2532 '┲'
+    [2, 0, 2, 2], // U+2533 '┳'
+    [1, 1, 1, 0], // U+2534 '┴'
+    [2, 1, 1, 0], // U+2535 '┵'
+    [1, 1, 2, 0], // U+2536 '┶'
+    [2, 1, 2, 0], // U+2537 '┷'
+    [1, 2, 1, 0], // U+2538 '┸'
+    [2, 2, 1, 0], // U+2539 '┹'
+    [1, 2, 2, 0], // U+253A '┺'
+    [2, 2, 2, 0], // U+253B '┻'
+    [1, 1, 1, 1], // U+253C '┼'
+    [2, 1, 1, 1], // U+253D '┽'
+    [1, 1, 2, 1], // U+253E '┾'
+    [2, 1, 2, 1], // U+253F '┿'
+    [1, 2, 1, 1], // U+2540 '╀'
+    [1, 1, 1, 2], // U+2541 '╁'
+    [1, 2, 1, 2], // U+2542 '╂'
+    [2, 2, 1, 1], // U+2543 '╃'
+    [1, 2, 2, 1], // U+2544 '╄'
+    [2, 1, 1, 2], // U+2545 '╅'
+    [1, 1, 2, 2], // U+2546 '╆'
+    [2, 2, 2, 1], // U+2547 '╇'
+    [2, 1, 2, 2], // U+2548 '╈'
+    [2, 2, 1, 2], // U+2549 '╉'
+    [1, 2, 2, 2], // U+254A '╊'
+    [2, 2, 2, 2], // U+254B '╋'
+    [1, 0, 1, 0], // U+254C '╌'
+    [2, 0, 2, 0], // U+254D '╍'
+    [0, 1, 0, 1], // U+254E '╎'
+    [0, 2, 0, 2], // U+254F '╏'
+    [3, 0, 3, 0], // U+2550 '═'
+    [0, 3, 0, 3], // U+2551 '║'
+    [0, 0, 3, 1], // U+2552 '╒'
+    [0, 0, 1, 3], // U+2553 '╓'
+    [0, 0, 3, 3], // U+2554 '╔'
+    [3, 0, 0, 1], // U+2555 '╕'
+    [1, 0, 0, 3], // U+2556 '╖'
+    [3, 0, 0, 3], // U+2557 '╗'
+    [0, 1, 3, 0], // U+2558 '╘'
+    [0, 3, 1, 0], // U+2559 '╙'
+    [0, 3, 3, 0], // U+255A '╚'
+    [3, 1, 0, 0], // U+255B '╛'
+    [1, 3, 0, 0], // U+255C '╜'
+    [3, 3, 0, 0], // U+255D '╝'
+    [0, 1, 3, 1], // U+255E '╞'
+    [0, 3, 1, 3], // U+255F '╟'
+    [0, 3, 3, 3], // U+2560 '╠'
+    [3, 1, 0, 1], // U+2561 '╡'
+    [1, 3, 0, 3], // U+2562 '╢'
+    [3, 3, 0, 3], // U+2563 '╣'
+    [3, 0, 3, 1], // U+2564 '╤'
+    [1, 0, 1, 3], // U+2565 '╥'
+    [3, 0, 3, 3], // U+2566 '╦'
+    [3, 1, 3, 0], // U+2567 '╧'
+    [1, 3, 1, 0], // U+2568 '╨'
+    [3, 3, 3, 0], // U+2569 '╩'
+    [3, 1, 3, 1], // U+256A '╪'
+    [1, 3, 1, 3], // U+256B '╫'
+    [3, 3, 3, 3], // U+256C '╬'
+    [0, 0, 1, 1], // U+256D '╭'
+    [1, 0, 0, 1], // U+256E '╮'
+    [1, 1, 0, 0], // U+256F '╯'
+    [0, 1, 1, 0], // U+2570 '╰'
+    [0, 0, 0, 0], // U+2571 '╱'
+    [0, 0, 0, 0], // U+2572 '╲'
+    [0, 0, 0, 0], // U+2573 '╳'
+    [1, 0, 0, 0], // U+2574 '╴'
+    [0, 1, 0, 0], // U+2575 '╵'
+    [0, 0, 1, 0], // U+2576 '╶'
+    [0, 0, 0, 1], // U+2577 '╷'
+    [2, 0, 0, 0], // U+2578 '╸'
+    [0, 2, 0, 0], // U+2579 '╹'
+    [0, 0, 2, 0], // U+257A '╺'
+    [0, 0, 0, 2], // U+257B '╻'
+    [1, 0, 2, 0], // U+257C '╼'
+    [0, 1, 0, 2], // U+257D '╽'
+    [2, 0, 1, 0], // U+257E '╾'
+    [0, 2, 0, 1], // U+257F '╿'
+];
@@ -0,0 +1,515 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use ratatui::prelude::*;
+
+use crate::{RatatuiCameraCell, RatatuiCameraCells};
+
+/// Identifies the recording format, followed by a version byte.
+const MAGIC: &[u8] = b"RCCELLS";
+const VERSION: u8 = 1;
+
+const FRAME_FULL: u8 = 0;
+const FRAME_DELTA: u8 = 1;
+
+const NO_SYMBOL: u32 = u32::MAX;
+
+/// The largest number of cells in a frame that will be read, so that a corrupt header cannot
+/// allocate an enormous grid.
+const MAX_FRAME_CELLS: usize = u16::MAX as usize * 64;
+
+/// A recording of frames of cells with their timing, in the crate's own lossless format, for
+/// replaying converted frames exactly (refer to `RatatuiCameraPlayer`) without rendering them.
+/// Record one with `RatatuiCameraRecorder::create_recording`, or with a
+/// `RatatuiCameraRecordingWriter`.
+///
+/// The format is binary: a header, then each frame's time and area followed by either all of its
+/// cells, or only the cells that changed since the previous frame (when the area is unchanged).
+/// Frames are fully expanded when loaded, so long recordings of large areas use a lot of memory.
+///
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RatatuiCameraRecording {
+    /// The recorded frames, in order of time.
+    pub frames: Vec<RatatuiCameraRecordedFrame>,
+}
+
+/// A single frame of a RatatuiCameraRecording.
+///
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RatatuiCameraRecordedFrame {
+    /// When the frame was recorded, relative to the start of the recording.
+    pub time: Duration,
+
+    /// The recorded cells.
+    pub cells: RatatuiCameraCells,
+}
+
+impl RatatuiCameraRecording {
+    /// The time of the last frame.
+    pub fn duration(&self) -> Duration {
+        self.frames
+            .last()
+            .map(|frame| frame.time)
+            .unwrap_or_default()
+    }
+
+    /// The last frame recorded at or before the given time.
+    pub fn frame_at(&self, time: Duration) -> Option<&RatatuiCameraRecordedFrame> {
+        let index = self.frames.partition_point(|frame| frame.time <= time);
+        self.frames.get(index.checked_sub(1)?)
+    }
+
+    /// Reads a recording until the end of the reader.
+    pub fn read_from(reader: impl Read) -> io::Result<Self> {
+        let mut reader = BufReader::new(reader);
+
+        let mut magic = [0; MAGIC.len()];
+        reader.read_exact(&mut magic)?;
+        if magic != MAGIC || read_u8(&mut reader)? != VERSION {
+            return Err(invalid_data(
+                "not a ratatui camera recording of a supported version",
+            ));
+        }
+
+        let mut frames: Vec<RatatuiCameraRecordedFrame> = Vec::new();
+
+        // The first byte of each frame is read separately to tell the end of the recording apart
+        // from a truncated frame.
+        let mut time_bytes = [0; 8];
+        while reader.read(&mut time_bytes[..1])? == 1 {
+            reader.read_exact(&mut time_bytes[1..])?;
+            let time = Duration::from_micros(u64::from_le_bytes(time_bytes));
+
+            let area = Rect {
+                x: read_u16(&mut reader)?,
+                y: read_u16(&mut reader)?,
+                width: read_u16(&mut reader)?,
+                height: read_u16(&mut reader)?,
+            };
+
+            if area.width as usize * area.height as usize > MAX_FRAME_CELLS {
+                return Err(invalid_data("frame area is too large"));
+            }
+
+            let cells = match read_u8(&mut reader)? {
+                FRAME_FULL => {
+                    let mut cells = RatatuiCameraCells::new(area);
+                    for cell in cells.cells.iter_mut() {
+                        *cell = read_cell(&mut reader)?;
+                    }
+                    cells
+                }
+                FRAME_DELTA => {
+                    let mut cells = match frames.last() {
+                        Some(previous_frame) if previous_frame.cells.area == area => {
+                            previous_frame.cells.clone()
+                        }
+                        _ => return Err(invalid_data("changed cells without a previous frame")),
+                    };
+
+                    for _ in 0..read_u32(&mut reader)? {
+                        let index = read_u32(&mut reader)? as usize;
+                        let Some(cell) = cells.cells.get_mut(index) else {
+                            return Err(invalid_data("changed cell outside of the frame"));
+                        };
+                        *cell = read_cell(&mut reader)?;
+                    }
+                    cells
+                }
+                _ => return Err(invalid_data("unknown frame kind")),
+            };
+
+            frames.push(RatatuiCameraRecordedFrame { time, cells });
+        }
+
+        Ok(Self { frames })
+    }
+
+    /// Writes the recording.
+    pub fn write_to(&self, writer: impl Write) -> io::Result<()> {
+        let mut writer = RatatuiCameraRecordingWriter::new(writer);
+
+        for frame in &self.frames {
+            writer.write_cells(frame.time, &frame.cells)?;
+        }
+
+        writer.finish()?;
+        Ok(())
+    }
+
+    /// Loads a recording from a file.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::read_from(File::open(path)?)
+    }
+
+    /// Saves the recording to a file.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.write_to(BufWriter::new(File::create(path)?))
+    }
+}
+
+/// Writes frames of cells as a RatatuiCameraRecording as they are produced, storing only the
+/// cells that changed since the previous frame where possible.
+///
+pub struct RatatuiCameraRecordingWriter<W: Write> {
+    writer: W,
+    has_header: bool,
+    previous_cells: Option<RatatuiCameraCells>,
+}
+
+impl<W: Write> RatatuiCameraRecordingWriter<W> {
+    /// Creates a writer that writes the recording into `writer`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            has_header: false,
+            previous_cells: None,
+        }
+    }
+
+    /// Writes a frame of cells, `time` after the start of the recording.
+    pub fn write_cells(&mut self, time: Duration, cells: &RatatuiCameraCells) -> io::Result<()> {
+        self.write_header()?;
+
+        let writer = &mut self.writer;
+        writer.write_all(&(time.as_micros() as u64).to_le_bytes())?;
+        for value in [
+            cells.area.x,
+            cells.area.y,
+            cells.area.width,
+            cells.area.height,
+        ] {
+            writer.write_all(&value.to_le_bytes())?;
+        }
+
+        let previous_cells = self
+            .previous_cells
+            .as_ref()
+            .filter(|previous_cells| previous_cells.area == cells.area);
+
+        match previous_cells {
+            Some(previous_cells) => {
+                let changed_cells: Vec<(usize, &RatatuiCameraCell)> = cells
+                    .cells
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, cell)| previous_cells.cells.get(*index) != Some(*cell))
+                    .collect();
+
+                writer.write_all(&[FRAME_DELTA])?;
+                writer.write_all(&(changed_cells.len() as u32).to_le_bytes())?;
+                for (index, cell) in changed_cells {
+                    writer.write_all(&(index as u32).to_le_bytes())?;
+                    write_cell(writer, cell)?;
+                }
+            }
+            None => {
+                writer.write_all(&[FRAME_FULL])?;
+                for cell in &cells.cells {
+                    write_cell(writer, cell)?;
+                }
+            }
+        }
+
+        match self.previous_cells.as_mut() {
+            Some(previous_cells) => previous_cells.clone_from(cells),
+            None => self.previous_cells = Some(cells.clone()),
+        }
+
+        self.writer.flush()
+    }
+
+    /// Writes the header (if no frames were written) and returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.write_header()?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        if !self.has_header {
+            self.writer.write_all(MAGIC)?;
+            self.writer.write_all(&[VERSION])?;
+            self.has_header = true;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_cell(writer: &mut impl Write, cell: &RatatuiCameraCell) -> io::Result<()> {
+    let symbol = cell.symbol.map_or(NO_SYMBOL, u32::from);
+    writer.write_all(&symbol.to_le_bytes())?;
+    write_color(writer, cell.fg)?;
+    write_color(writer, cell.bg)?;
+    writer.write_all(&cell.modifier.bits().to_le_bytes())
+}
+
+fn read_cell(reader: &mut impl Read) -> io::Result<RatatuiCameraCell> {
+    let symbol = match read_u32(reader)? {
+        NO_SYMBOL => None,
+        symbol => Some(char::from_u32(symbol).ok_or_else(|| invalid_data("invalid symbol"))?),
+    };
+
+    Ok(RatatuiCameraCell {
+        symbol,
+        fg: read_color(reader)?,
+        bg: read_color(reader)?,
+        modifier: Modifier::from_bits_truncate(read_u16(reader)?),
+    })
+}
+
+/// Colors are written as a tag byte, followed by the index or RGB components if needed.
+fn write_color(writer: &mut impl Write, color: Option<Color>) -> io::Result<()> {
+    let Some(color) = color else {
+        return writer.write_all(&[0]);
+    };
+
+    match color {
+        Color::Reset => writer.write_all(&[1]),
+        Color::Black => writer.write_all(&[2]),
+        Color::Red => writer.write_all(&[3]),
+        Color::Green => writer.write_all(&[4]),
+        Color::Yellow => writer.write_all(&[5]),
+        Color::Blue => writer.write_all(&[6]),
+        Color::Magenta => writer.write_all(&[7]),
+        Color::Cyan => writer.write_all(&[8]),
+        Color::Gray => writer.write_all(&[9]),
+        Color::DarkGray => writer.write_all(&[10]),
+        Color::LightRed => writer.write_all(&[11]),
+        Color::LightGreen => writer.write_all(&[12]),
+        Color::LightYellow => writer.write_all(&[13]),
+        Color::LightBlue => writer.write_all(&[14]),
+        Color::LightMagenta => writer.write_all(&[15]),
+        Color::LightCyan => writer.write_all(&[16]),
+        Color::White => writer.write_all(&[17]),
+        Color::Indexed(index) => writer.write_all(&[18, index]),
+        Color::Rgb(r, g, b) => writer.write_all(&[19, r, g, b]),
+    }
+}
+
+fn read_color(reader: &mut impl Read) -> io::Result<Option<Color>> {
+    let color = match read_u8(reader)? {
+        0 => return Ok(None),
+        1 => Color::Reset,
+        2 => Color::Black,
+        3 => Color::Red,
+        4 => Color::Green,
+        5 => Color::Yellow,
+        6 => Color::Blue,
+        7 => Color::Magenta,
+        8 => Color::Cyan,
+        9 => Color::Gray,
+        10 => Color::DarkGray,
+        11 => Color::LightRed,
+        12 => Color::LightGreen,
+        13 => Color::LightYellow,
+        14 => Color::LightBlue,
+        15 => Color::LightMagenta,
+        16 => Color::LightCyan,
+        17 => Color::White,
+        18 => Color::Indexed(read_u8(reader)?),
+        19 => Color::Rgb(read_u8(reader)?, read_u8(reader)?, read_u8(reader)?),
+        _ => return Err(invalid_data("unknown color")),
+    };
+
+    Ok(Some(color))
+}
+
+fn read_u8(reader: &mut impl Read) -> io::Result<u8> {
+    let mut bytes = [0; 1];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes[0])
+}
+
+fn read_u16(reader: &mut impl Read) -> io::Result<u16> {
+    let mut bytes = [0; 2];
+    reader.read_exact(&mut bytes)?;
+    Ok(u16::from_le_bytes(bytes))
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cells(area: Rect, symbol: char) -> RatatuiCameraCells {
+        let mut cells = RatatuiCameraCells::new(area);
+        for (index, cell) in cells.cells.iter_mut().enumerate() {
+            *cell = RatatuiCameraCell {
+                bg: Some(Color::Indexed(index as u8)),
+                modifier: Modifier::BOLD,
+                ..RatatuiCameraCell::new(symbol, Color::Rgb(index as u8, 2, 3))
+            };
+        }
+        cells
+    }
+
+    fn recording() -> RatatuiCameraRecording {
+        let area = Rect::new(1, 2, 4, 3);
+        let first = cells(area, 'a');
+
+        let mut second = first.clone();
+        second.cells[5] = RatatuiCameraCell::new('b', Color::Reset);
+        second.cells[11] = RatatuiCameraCell::default();
+
+        let frames = [
+            (0, first),
+            (40, second),
+            (80, cells(Rect::new(0, 0, 2, 2), 'c')),
+        ];
+
+        RatatuiCameraRecording {
+            frames: frames
+                .into_iter()
+                .map(|(millis, cells)| RatatuiCameraRecordedFrame {
+                    time: Duration::from_millis(millis),
+                    cells,
+                })
+                .collect(),
+        }
+    }
+
+    fn write(recording: &RatatuiCameraRecording) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        recording.write_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    /// A header followed by the start of a frame with the given area and kind.
+    fn frame_start(area: Rect, kind: u8) -> Vec<u8> {
+        let mut bytes = [MAGIC, &[VERSION], &0u64.to_le_bytes()].concat();
+        for value in [area.x, area.y, area.width, area.height] {
+            bytes.extend(value.to_le_bytes());
+        }
+        bytes.push(kind);
+        bytes
+    }
+
+    fn read_error(bytes: &[u8]) -> io::ErrorKind {
+        RatatuiCameraRecording::read_from(bytes).unwrap_err().kind()
+    }
+
+    #[test]
+    fn round_trip() {
+        let recording = recording();
+        let bytes = write(&recording);
+
+        assert_eq!(
+            RatatuiCameraRecording::read_from(&bytes[..]).unwrap(),
+            recording
+        );
+    }
+
+    #[test]
+    fn unchanged_areas_are_written_as_deltas() {
+        let recording = recording();
+        let frame_header_size = frame_start(Rect::ZERO, FRAME_FULL).len() - MAGIC.len() - 1;
+
+        let mut single_frame = recording.clone();
+        single_frame.frames.truncate(1);
+        let mut two_frames = recording.clone();
+        two_frames.frames.truncate(2);
+
+        // The count of changed cells, then the two changed cells, each with its index, a symbol,
+        // an fg tag, a bg tag, and modifiers.
+        let delta_size = write(&two_frames).len() - write(&single_frame).len();
+        assert_eq!(delta_size, frame_header_size + 4 + 2 * (4 + 4 + 1 + 1 + 2));
+    }
+
+    #[test]
+    fn empty_recording() {
+        let bytes = write(&RatatuiCameraRecording::default());
+
+        assert_eq!(bytes, [MAGIC, &[VERSION]].concat());
+        assert!(RatatuiCameraRecording::read_from(&bytes[..])
+            .unwrap()
+            .frames
+            .is_empty());
+    }
+
+    #[test]
+    fn truncated_recording() {
+        let bytes = write(&recording());
+
+        assert_eq!(
+            read_error(&bytes[..bytes.len() - 1]),
+            io::ErrorKind::UnexpectedEof
+        );
+        assert_eq!(read_error(&bytes[..3]), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn invalid_header() {
+        let mut bytes = write(&recording());
+        bytes[MAGIC.len()] = VERSION + 1;
+
+        assert_eq!(read_error(&bytes), io::ErrorKind::InvalidData);
+        assert_eq!(read_error(b"NOTCELLS"), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn unknown_frame_kind() {
+        let bytes = frame_start(Rect::new(0, 0, 1, 1), 7);
+
+        assert_eq!(read_error(&bytes), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn delta_frame_without_previous_frame() {
+        let mut bytes = frame_start(Rect::new(0, 0, 1, 1), FRAME_DELTA);
+        bytes.extend(0u32.to_le_bytes());
+
+        assert_eq!(read_error(&bytes), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn delta_frame_with_changed_area() {
+        let mut recording = recording();
+        recording.frames.truncate(1);
+        let mut bytes = write(&recording);
+
+        bytes.extend(&frame_start(Rect::new(0, 0, 1, 1), FRAME_DELTA)[MAGIC.len() + 1..]);
+        bytes.extend(0u32.to_le_bytes());
+
+        assert_eq!(read_error(&bytes), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn oversized_frame() {
+        let bytes = frame_start(Rect::new(0, 0, u16::MAX, u16::MAX), FRAME_FULL);
+
+        assert_eq!(read_error(&bytes), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn frame_at() {
+        let recording = recording();
+        let frame_time = |millis| {
+            recording
+                .frame_at(Duration::from_millis(millis))
+                .map(|frame| frame.time.as_millis())
+        };
+
+        assert_eq!(recording.duration(), Duration::from_millis(80));
+        assert_eq!(frame_time(0), Some(0));
+        assert_eq!(frame_time(39), Some(0));
+        assert_eq!(frame_time(40), Some(40));
+        assert_eq!(frame_time(1000), Some(80));
+
+        let mut late_start = recording.clone();
+        late_start.frames[0].time = Duration::from_millis(10);
+        assert!(late_start.frame_at(Duration::from_millis(5)).is_none());
+    }
+}
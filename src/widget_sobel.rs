@@ -0,0 +1,99 @@
+use bevy::color::{ColorToPacked, LinearRgba, Srgba};
+use bevy::math::Vec3;
+use image::{DynamicImage, Rgba, RgbaImage};
+
+use crate::widget_cells::as_rgba8;
+use crate::RatatuiCameraEdgeDetection;
+
+// The kernels and neighbor offsets below are the same as in `shaders/sobel.wgsl`, and are indexed
+// the same way.
+
+const SOBEL_HORIZONTAL: [f32; 9] = [1.0, 0.0, -1.0, 2.0, 0.0, -2.0, 1.0, 0.0, -1.0];
+const SOBEL_VERTICAL: [f32; 9] = [1.0, 2.0, 1.0, 0.0, 0.0, 0.0, -1.0, -2.0, -1.0];
+const SOBEL_FORWARD: [f32; 9] = [0.0, 1.0, 2.0, -1.0, 0.0, 1.0, -2.0, -1.0, 0.0];
+const SOBEL_BACKWARD: [f32; 9] = [2.0, 1.0, 0.0, 1.0, 0.0, -1.0, 0.0, -1.0, -2.0];
+
+const NEIGHBORS: [(f32, f32); 9] = [
+    (-1.0, 1.0),
+    (0.0, 1.0),
+    (1.0, 1.0),
+    (-1.0, 0.0),
+    (0.0, 0.0),
+    (1.0, 0.0),
+    (-1.0, -1.0),
+    (0.0, -1.0),
+    (1.0, -1.0),
+];
+
+impl RatatuiCameraEdgeDetection {
+    /// Detects edges in an image on the CPU, producing a sobel image like the one produced by the
+    /// edge detection step of the render pipeline, for use with `RatatuiCameraWidget::from_image`.
+    ///
+    /// Only the color of the image can be used, so the depth and normal settings are ignored; if
+    /// `color_enabled` is false, no edges are detected. Pixels sampled outside of the image are
+    /// clamped to its border.
+    pub fn detect_edges(&self, image: &DynamicImage) -> DynamicImage {
+        let image = as_rgba8(image);
+        let (width, height) = image.dimensions();
+        let mut sobel_image = RgbaImage::new(width, height);
+
+        if !self.color_enabled || width == 0 || height == 0 {
+            return DynamicImage::ImageRgba8(sobel_image);
+        }
+
+        // The shader samples the color texture as linear values.
+        let linear_image: Vec<Vec3> = image
+            .pixels()
+            .map(|pixel| {
+                let color = LinearRgba::from(Srgba::rgb_u8(pixel[0], pixel[1], pixel[2]));
+                Vec3::new(color.red, color.green, color.blue)
+            })
+            .collect();
+
+        let sample = |x: f32, y: f32| {
+            // Truncated toward zero like the shader's conversion to integer coordinates.
+            let x = (x as i32).clamp(0, width as i32 - 1) as usize;
+            let y = (y as i32).clamp(0, height as i32 - 1) as usize;
+            linear_image[y * width as usize + x]
+        };
+
+        for (x, y, sobel_pixel) in sobel_image.enumerate_pixels_mut() {
+            let (frag_x, frag_y) = (x as f32 + 0.5, y as f32 + 0.5);
+
+            let samples: [Vec3; 9] = std::array::from_fn(|index| {
+                let (offset_x, offset_y) = NEIGHBORS[index];
+                sample(
+                    frag_x + offset_x * self.thickness,
+                    frag_y + offset_y * self.thickness,
+                )
+            });
+
+            let edge = [
+                SOBEL_HORIZONTAL,
+                SOBEL_VERTICAL,
+                SOBEL_FORWARD,
+                SOBEL_BACKWARD,
+            ]
+            .map(|kernel| {
+                let gradient: Vec3 = samples
+                    .iter()
+                    .zip(kernel)
+                    .map(|(sample, weight)| *sample * weight)
+                    .sum();
+                let strength = gradient.length();
+
+                if strength < self.color_threshold {
+                    0.0
+                } else {
+                    strength
+                }
+            });
+
+            // The pipeline's sobel texture is sRGB, so the first three channels are encoded.
+            let encoded = Srgba::from(LinearRgba::new(edge[0], edge[1], edge[2], edge[3]));
+            *sobel_pixel = Rgba(encoded.to_u8_array());
+        }
+
+        DynamicImage::ImageRgba8(sobel_image)
+    }
+}
@@ -0,0 +1,87 @@
+use ratatui::prelude::*;
+use ratatui::widgets::WidgetRef;
+
+use crate::RatatuiCameraWidget;
+
+/// Ratatui widget that draws several RatatuiCameraWidgets into the same area, in the order they
+/// were added, so that later layers are drawn on top of earlier ones. Each layer keeps its own
+/// strategy, edge detection, and layout, so for example a world camera using the luminance
+/// strategy can be drawn under a HUD camera using halfblocks.
+///
+/// Layers other than the first should use `RatatuiCameraTransparency::Transparent` (and be cleared
+/// to a transparent color) for the layers below them to show through. The labels and glyphs of
+/// every layer are drawn after all of the images, so they are never covered by a higher layer.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui::terminal::RatatuiContext;
+/// # use bevy_ratatui_camera::{RatatuiCameraStack, RatatuiCameraWidget};
+/// #
+/// # #[derive(Component)]
+/// # struct WorldCamera;
+/// # #[derive(Component)]
+/// # struct HudCamera;
+/// #
+/// fn draw_scene_system(
+///     mut ratatui: ResMut<RatatuiContext>,
+///     world_widget: Query<&RatatuiCameraWidget, With<WorldCamera>>,
+///     hud_widget: Query<&RatatuiCameraWidget, With<HudCamera>>,
+/// ) -> std::io::Result<()> {
+///     ratatui.draw(|frame| {
+///         let stack = RatatuiCameraStack::new()
+///             .with_layer(world_widget.single())
+///             .with_layer(hud_widget.single());
+///
+///         frame.render_widget(&stack, frame.area());
+///     })?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Default)]
+pub struct RatatuiCameraStack<'a> {
+    /// The layers to draw, from bottom to top.
+    pub layers: Vec<&'a RatatuiCameraWidget>,
+}
+
+impl<'a> RatatuiCameraStack<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a layer on top of the existing layers.
+    pub fn with_layer(mut self, layer: &'a RatatuiCameraWidget) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    /// Adds several layers on top of the existing layers, in order from bottom to top.
+    pub fn with_layers(
+        mut self,
+        layers: impl IntoIterator<Item = &'a RatatuiCameraWidget>,
+    ) -> Self {
+        self.layers.extend(layers);
+        self
+    }
+}
+
+impl WidgetRef for RatatuiCameraStack<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        for layer in &self.layers {
+            layer.render_image(area, buf);
+        }
+
+        for layer in &self.layers {
+            layer.render_overlays(area, buf);
+        }
+    }
+}
+
+impl Widget for RatatuiCameraStack<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
@@ -0,0 +1,324 @@
+//! Golden tests that convert synthetic images with each strategy and edge detection setting, draw
+//! them into a ratatui `Buffer`, and compare the result against the snapshots stored in
+//! `tests/snapshots`. Nothing is rendered with Bevy, so these run without a GPU.
+//!
+//! A missing or mismatching snapshot fails the test, and the new output is written next to it with
+//! a `.snap.new` extension for comparison. To write new snapshots or accept intentional changes,
+//! run the tests with `UPDATE_SNAPSHOTS=1`.
+
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use bevy_ratatui_camera::{
+    EdgeCharacters, FitMode, LayoutAlignment, RatatuiCameraEdgeDetection, RatatuiCameraLayout,
+    RatatuiCameraStrategy, RatatuiCameraWidget,
+};
+use image::{DynamicImage, Rgba, RgbaImage};
+use ratatui::prelude::*;
+use ratatui::widgets::Widget;
+
+const IMAGE_SIZE: u32 = 64;
+
+/// The area the widgets are drawn in, offset within a larger buffer so that the position of the
+/// drawn image (and anything drawn outside of the area) is part of each snapshot.
+const AREA: Rect = Rect::new(2, 1, 24, 10);
+const BUFFER_AREA: Rect = Rect::new(0, 0, 28, 12);
+
+fn images() -> Vec<(&'static str, DynamicImage)> {
+    vec![
+        (
+            "horizontal_gradient",
+            image_from_fn(|x, _| {
+                let value = (x * 255 / (IMAGE_SIZE - 1)) as u8;
+                [value, value / 2, 255 - value]
+            }),
+        ),
+        (
+            "vertical_gradient",
+            image_from_fn(|_, y| {
+                let value = (y * 255 / (IMAGE_SIZE - 1)) as u8;
+                [value, value, value]
+            }),
+        ),
+        (
+            "checkerboard",
+            image_from_fn(|x, y| {
+                if (x / 8 + y / 8) % 2 == 0 {
+                    [255, 255, 255]
+                } else {
+                    [0, 0, 0]
+                }
+            }),
+        ),
+        ("circle", circle_image()),
+        ("line_0", line_image(0.0)),
+        ("line_45", line_image(45.0)),
+        ("line_90", line_image(90.0)),
+        ("line_135", line_image(135.0)),
+    ]
+}
+
+fn strategies() -> Vec<(&'static str, RatatuiCameraStrategy)> {
+    vec![
+        ("halfblocks", RatatuiCameraStrategy::HalfBlocks),
+        ("braille", RatatuiCameraStrategy::luminance_braille()),
+        ("misc", RatatuiCameraStrategy::luminance_misc()),
+        ("shading", RatatuiCameraStrategy::luminance_shading()),
+        ("none", RatatuiCameraStrategy::None),
+    ]
+}
+
+/// How the edges drawn by the strategies are produced.
+enum Edges {
+    /// No edge detection.
+    Disabled,
+
+    /// Edges detected on the CPU from the image itself.
+    Detected(RatatuiCameraEdgeDetection),
+
+    /// A synthetic sobel image, with a vertical, horizontal, and both diagonal edges in known
+    /// places, independent of the image.
+    Synthetic(RatatuiCameraEdgeDetection),
+}
+
+fn edges() -> Vec<(&'static str, Edges)> {
+    let single = RatatuiCameraEdgeDetection {
+        edge_characters: EdgeCharacters::Single('#'),
+        edge_color: Some(Color::Magenta),
+        ..Default::default()
+    };
+
+    vec![
+        ("no_edges", Edges::Disabled),
+        (
+            "detected_edges",
+            Edges::Detected(RatatuiCameraEdgeDetection::default()),
+        ),
+        ("detected_single_edges", Edges::Detected(single)),
+        (
+            "synthetic_edges",
+            Edges::Synthetic(RatatuiCameraEdgeDetection::default()),
+        ),
+    ]
+}
+
+#[test]
+fn strategies_and_edges() {
+    let mut failures = Vec::new();
+
+    for (image_name, image) in images() {
+        for (strategy_name, strategy) in strategies() {
+            for (edges_name, edges) in edges() {
+                let widget = RatatuiCameraWidget::from_image(image.clone(), strategy.clone());
+                let widget = match edges {
+                    Edges::Disabled => widget,
+                    Edges::Detected(edge_detection) => widget.with_edge_detection(edge_detection),
+                    Edges::Synthetic(edge_detection) => widget
+                        .with_sobel_image(synthetic_sobel_image())
+                        .with_edge_detection(edge_detection),
+                };
+
+                let name = format!("{image_name}__{strategy_name}__{edges_name}");
+                failures.extend(check_snapshot(&name, &draw(&widget)));
+            }
+        }
+    }
+
+    assert_failures(failures);
+}
+
+#[test]
+fn layouts() {
+    let wide_image = image_from_fn(|x, y| [(x * 4) as u8, (y * 4) as u8, 128]);
+    let wide_image = image::imageops::crop_imm(&wide_image.to_rgba8(), 0, 16, 64, 24).to_image();
+    let wide_image = DynamicImage::ImageRgba8(wide_image);
+
+    let layouts = [
+        ("fit_center", FitMode::Fit, LayoutAlignment::Center),
+        ("fit_top_left", FitMode::Fit, LayoutAlignment::TopLeft),
+        (
+            "fit_bottom_right",
+            FitMode::Fit,
+            LayoutAlignment::BottomRight,
+        ),
+        ("fill_center", FitMode::Fill, LayoutAlignment::Center),
+        ("stretch", FitMode::Stretch, LayoutAlignment::Center),
+        ("integer_center", FitMode::Integer, LayoutAlignment::Center),
+    ];
+
+    let mut failures = Vec::new();
+
+    for (image_name, image) in [("square", circle_image()), ("wide", wide_image)] {
+        for (layout_name, fit, alignment) in layouts {
+            let mut widget = RatatuiCameraWidget::from_image(
+                image.clone(),
+                RatatuiCameraStrategy::luminance_misc(),
+            );
+            widget.layout = RatatuiCameraLayout {
+                fit,
+                alignment,
+                ..Default::default()
+            };
+
+            let name = format!("layout__{image_name}__{layout_name}");
+            failures.extend(check_snapshot(&name, &draw(&widget)));
+        }
+    }
+
+    assert_failures(failures);
+}
+
+fn image_from_fn(pixel: impl Fn(u32, u32) -> [u8; 3]) -> DynamicImage {
+    DynamicImage::ImageRgba8(RgbaImage::from_fn(IMAGE_SIZE, IMAGE_SIZE, |x, y| {
+        let [r, g, b] = pixel(x, y);
+        Rgba([r, g, b, 255])
+    }))
+}
+
+/// A red circle on a dark blue background.
+fn circle_image() -> DynamicImage {
+    let center = IMAGE_SIZE as f32 / 2.0;
+
+    image_from_fn(|x, y| {
+        let (dx, dy) = (x as f32 + 0.5 - center, y as f32 + 0.5 - center);
+        if (dx * dx + dy * dy).sqrt() < IMAGE_SIZE as f32 / 3.0 {
+            [230, 60, 40]
+        } else {
+            [10, 20, 60]
+        }
+    })
+}
+
+/// A white line, three pixels wide, through the center of a black image, at an angle in degrees
+/// counterclockwise from horizontal.
+fn line_image(angle: f32) -> DynamicImage {
+    let (sin, cos) = angle.to_radians().sin_cos();
+    let center = IMAGE_SIZE as f32 / 2.0;
+
+    image_from_fn(|x, y| {
+        let (dx, dy) = (x as f32 + 0.5 - center, center - (y as f32 + 0.5));
+        let distance = (dx * sin - dy * cos).abs();
+        if distance < 1.5 {
+            [255, 255, 255]
+        } else {
+            [0, 0, 0]
+        }
+    })
+}
+
+/// A sobel image with a vertical edge down the left quarter, a horizontal edge across the top
+/// quarter, a forward diagonal edge in the lower right quadrant, and a backward diagonal edge in
+/// the lower left quadrant.
+fn synthetic_sobel_image() -> DynamicImage {
+    let quarter = IMAGE_SIZE / 4;
+    let half = IMAGE_SIZE / 2;
+
+    DynamicImage::ImageRgba8(RgbaImage::from_fn(IMAGE_SIZE, IMAGE_SIZE, |x, y| {
+        let vertical = x.abs_diff(quarter) < 2;
+        let horizontal = y.abs_diff(quarter) < 2;
+        let forward = x >= half && y >= half && (x - half).abs_diff(IMAGE_SIZE - 1 - y) < 2;
+        let backward = x < half && y >= half && x.abs_diff(y - half) < 2;
+
+        let strength = |is_edge: bool| if is_edge { 255 } else { 0 };
+        Rgba([
+            strength(vertical),
+            strength(horizontal),
+            strength(forward),
+            strength(backward),
+        ])
+    }))
+}
+
+fn draw(widget: &RatatuiCameraWidget) -> Buffer {
+    let mut buffer = Buffer::empty(BUFFER_AREA);
+    widget.render(AREA, &mut buffer);
+    buffer
+}
+
+/// Serializes a buffer as a grid of symbols, followed by grids of foreground and background colors
+/// (abbreviated, with a legend), so that snapshot diffs show where each change happened.
+fn serialize(buffer: &Buffer) -> String {
+    let mut colors: Vec<Color> = Vec::new();
+    let mut color_key = |color: Color| {
+        let index = colors.iter().position(|c| *c == color).unwrap_or_else(|| {
+            colors.push(color);
+            colors.len() - 1
+        });
+        color_key_for_index(index)
+    };
+
+    let width = buffer.area.width as usize;
+    let (mut symbols, mut fg, mut bg) = (String::new(), String::new(), String::new());
+
+    for row in buffer.content.chunks(width) {
+        for cell in row {
+            symbols.push_str(cell.symbol());
+            fg.push(color_key(cell.fg));
+            bg.push(color_key(cell.bg));
+        }
+        symbols.push('\n');
+        fg.push('\n');
+        bg.push('\n');
+    }
+
+    let mut output = format!("symbols:\n{symbols}\nfg:\n{fg}\nbg:\n{bg}\ncolors:\n");
+    for (index, color) in colors.iter().enumerate() {
+        let _ = writeln!(output, "{} {color:?}", color_key_for_index(index));
+    }
+
+    output
+}
+
+fn color_key_for_index(index: usize) -> char {
+    const KEYS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    KEYS.get(index)
+        .map(|key| *key as char)
+        .or_else(|| char::from_u32(0x100 + index as u32))
+        .unwrap_or('?')
+}
+
+/// Compares a buffer against its stored snapshot, returning a description of the mismatch.
+fn check_snapshot(name: &str, buffer: &Buffer) -> Option<String> {
+    let directory = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots");
+    let path = directory.join(format!("{name}.snap"));
+    let new_path = directory.join(format!("{name}.snap.new"));
+    let actual = serialize(buffer);
+
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some_and(|value| value != "0");
+
+    match std::fs::read_to_string(&path) {
+        Ok(expected) if expected == actual => {
+            let _ = std::fs::remove_file(&new_path);
+            None
+        }
+        _ if update => {
+            std::fs::create_dir_all(&directory).expect("failed to create snapshot directory");
+            std::fs::write(&path, &actual).expect("failed to write snapshot");
+            let _ = std::fs::remove_file(&new_path);
+            None
+        }
+        result => {
+            std::fs::create_dir_all(&directory).expect("failed to create snapshot directory");
+            std::fs::write(&new_path, &actual).expect("failed to write new snapshot");
+            let problem = match result {
+                Ok(_) => "output differs from",
+                Err(_) => "missing snapshot",
+            };
+            Some(format!(
+                "{name}: {problem} {}, new output written to {}",
+                path.display(),
+                new_path.display(),
+            ))
+        }
+    }
+}
+
+fn assert_failures(failures: Vec<String>) {
+    assert!(
+        failures.is_empty(),
+        "{} snapshot(s) missing or mismatched (rerun with UPDATE_SNAPSHOTS=1 to accept):\n{}",
+        failures.len(),
+        failures.join("\n"),
+    );
+}